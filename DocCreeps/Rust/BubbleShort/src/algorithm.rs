@@ -0,0 +1,234 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::observer::{NoopObserver, SortObserver};
+use crate::parallel::{par_merge_sort, par_merge_sort_by, par_quick_sort, par_quick_sort_by, Parallelism};
+use crate::radix::{counting_sort_observed, radix_sort_observed, RadixKey};
+use crate::sorts::{
+    bubble_sort_observed, heap_sort_observed, insertion_sort_observed, merge_sort_observed, quick_sort_observed,
+    selection_sort_observed, shell_sort_observed, tim_sort_observed, GapSequence, PivotStrategy,
+};
+use crate::stats::SortStats;
+
+/// Liste des algorithmes disponibles, dans l'ordre du menu.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Algorithm {
+    Bubble,
+    Selection,
+    Insertion,
+    Merge,
+    Quick(PivotStrategy),
+    Heap,
+    Shell(GapSequence),
+    Counting,
+    Radix,
+    Tim,
+    ParallelMerge(Parallelism),
+    ParallelQuick(Parallelism),
+}
+
+impl Algorithm {
+    /// Tous les algorithmes, dans l'ordre du menu interactif.
+    pub const ALL: [Algorithm; 12] = [
+        Algorithm::Bubble,
+        Algorithm::Selection,
+        Algorithm::Insertion,
+        Algorithm::Merge,
+        Algorithm::Quick(PivotStrategy::Ninther),
+        Algorithm::Heap,
+        Algorithm::Shell(GapSequence::Ciura),
+        Algorithm::Counting,
+        Algorithm::Radix,
+        Algorithm::Tim,
+        Algorithm::ParallelMerge(Parallelism::DEFAULT),
+        Algorithm::ParallelQuick(Parallelism::DEFAULT),
+    ];
+
+    /// Nom court utilisé en ligne de commande (`--algo quick`), sans paramètre.
+    pub fn name(self) -> &'static str {
+        match self {
+            Algorithm::Bubble => "bubble",
+            Algorithm::Selection => "selection",
+            Algorithm::Insertion => "insertion",
+            Algorithm::Merge => "merge",
+            Algorithm::Quick(_) => "quick",
+            Algorithm::Heap => "heap",
+            Algorithm::Shell(_) => "shell",
+            Algorithm::Counting => "counting",
+            Algorithm::Radix => "radix",
+            Algorithm::Tim => "tim",
+            Algorithm::ParallelMerge(_) => "par-merge",
+            Algorithm::ParallelQuick(_) => "par-quick",
+        }
+    }
+
+    /// Libellé affiché dans le menu.
+    pub fn label(self) -> &'static str {
+        match self {
+            Algorithm::Bubble => "Bubble Sort",
+            Algorithm::Selection => "Selection Sort",
+            Algorithm::Insertion => "Insertion Sort",
+            Algorithm::Merge => "Merge Sort",
+            Algorithm::Quick(_) => "Quick Sort",
+            Algorithm::Heap => "Heap Sort",
+            Algorithm::Shell(_) => "Shell Sort",
+            Algorithm::Counting => "Counting Sort",
+            Algorithm::Radix => "Radix Sort (LSD)",
+            Algorithm::Tim => "Tim Sort (hybride)",
+            Algorithm::ParallelMerge(_) => "Merge Sort parallèle",
+            Algorithm::ParallelQuick(_) => "Quick Sort parallèle",
+        }
+    }
+
+    /// Vrai si l'algorithme préserve l'ordre relatif des éléments égaux.
+    pub fn is_stable(self) -> bool {
+        matches!(
+            self,
+            Algorithm::Bubble
+                | Algorithm::Insertion
+                | Algorithm::Merge
+                | Algorithm::Counting
+                | Algorithm::Radix
+                | Algorithm::Tim
+                | Algorithm::ParallelMerge(_)
+        )
+    }
+
+    /// Version séquentielle d'un tri parallèle, l'algorithme lui-même sinon.
+    pub fn sequential(self) -> Algorithm {
+        match self {
+            Algorithm::ParallelMerge(_) => Algorithm::Merge,
+            Algorithm::ParallelQuick(_) => Algorithm::Quick(PivotStrategy::default()),
+            other => other,
+        }
+    }
+
+    /// Remplace les réglages de parallélisme d'un tri parallèle.
+    pub fn with_parallelism(self, parallelism: Parallelism) -> Algorithm {
+        match self {
+            Algorithm::ParallelMerge(_) => Algorithm::ParallelMerge(parallelism),
+            Algorithm::ParallelQuick(_) => Algorithm::ParallelQuick(parallelism),
+            other => other,
+        }
+    }
+
+    /// Vrai si l'algorithme est en O(n²) dans le cas moyen.
+    pub fn is_quadratic(self) -> bool {
+        matches!(self, Algorithm::Bubble | Algorithm::Selection | Algorithm::Insertion)
+    }
+
+    /// Vrai si l'algorithme ne procède que par comparaisons, et accepte
+    /// donc une fonction de comparaison quelconque.
+    pub fn is_comparison(self) -> bool {
+        !matches!(self, Algorithm::Counting | Algorithm::Radix)
+    }
+
+    /// Trie `arr` avec cet algorithme.
+    pub fn sort<T: Ord + RadixKey + Send>(self, arr: &mut [T]) {
+        match self {
+            Algorithm::ParallelMerge(parallelism) => par_merge_sort(arr, parallelism),
+            Algorithm::ParallelQuick(parallelism) => par_quick_sort(arr, parallelism),
+            _ => self.sort_observed(arr, &mut NoopObserver),
+        }
+    }
+
+    /// Trie `arr` selon `compare` ; les tris parallèles s'exécutent vraiment en parallèle.
+    ///
+    /// Renvoie une erreur pour les tris non comparatifs (voir [`Algorithm::is_comparison`]).
+    pub fn sort_by<T, F>(self, arr: &mut [T], compare: F) -> Result<(), String>
+    where
+        T: Clone + Send,
+        F: Fn(&T, &T) -> Ordering + Sync,
+    {
+        match self {
+            Algorithm::ParallelMerge(parallelism) => par_merge_sort_by(arr, parallelism, compare),
+            Algorithm::ParallelQuick(parallelism) => par_quick_sort_by(arr, parallelism, compare),
+            _ => return self.sort_observed_by(arr, compare, &mut NoopObserver),
+        }
+        Ok(())
+    }
+
+    /// Trie `arr` en signalant chaque opération élémentaire à `observer`.
+    ///
+    /// Les tris parallèles sont observés via leur version séquentielle,
+    /// qui effectue exactement les mêmes opérations.
+    pub fn sort_observed<T, O>(self, arr: &mut [T], observer: &mut O)
+    where
+        T: Ord + RadixKey,
+        O: SortObserver<T>,
+    {
+        match self {
+            Algorithm::Counting => counting_sort_observed(arr, |&x| x, observer),
+            Algorithm::Radix => radix_sort_observed(arr, |&x| x, observer),
+            _ => self
+                .sort_observed_by(arr, T::cmp, observer)
+                .expect("algorithme comparatif"),
+        }
+    }
+
+    /// Trie `arr` selon `compare` en signalant chaque opération à `observer`.
+    ///
+    /// Renvoie une erreur pour les tris non comparatifs (voir [`Algorithm::is_comparison`]).
+    pub fn sort_observed_by<T, F, O>(self, arr: &mut [T], mut compare: F, observer: &mut O) -> Result<(), String>
+    where
+        T: Clone,
+        F: FnMut(&T, &T) -> Ordering,
+        O: SortObserver<T>,
+    {
+        match self {
+            Algorithm::ParallelMerge(_) | Algorithm::ParallelQuick(_) => {
+                return self.sequential().sort_observed_by(arr, compare, observer);
+            }
+            Algorithm::Bubble => bubble_sort_observed(arr, &mut compare, observer),
+            Algorithm::Selection => selection_sort_observed(arr, &mut compare, observer),
+            Algorithm::Insertion => insertion_sort_observed(arr, &mut compare, observer),
+            Algorithm::Merge => merge_sort_observed(arr, &mut compare, observer),
+            Algorithm::Quick(pivot) => quick_sort_observed(arr, pivot, &mut compare, observer),
+            Algorithm::Heap => heap_sort_observed(arr, &mut compare, observer),
+            Algorithm::Shell(gaps) => shell_sort_observed(arr, gaps, &mut compare, observer),
+            Algorithm::Tim => tim_sort_observed(arr, &mut compare, observer),
+            Algorithm::Counting | Algorithm::Radix => {
+                return Err(format!("{} n'accepte pas de fonction de comparaison", self.label()));
+            }
+        }
+        Ok(())
+    }
+
+    /// Trie `arr` en mode instrumenté et renvoie le coût de l'opération.
+    pub fn sort_instrumented<T: Ord + RadixKey>(self, arr: &mut [T]) -> SortStats {
+        let mut stats = SortStats::default();
+        self.sort_observed(arr, &mut stats);
+        stats
+    }
+}
+
+impl fmt::Display for Algorithm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Algorithm::Quick(pivot) => write!(f, "{}:{}", self.name(), pivot),
+            Algorithm::Shell(gaps) => write!(f, "{}:{}", self.name(), gaps),
+            _ => f.write_str(self.name()),
+        }
+    }
+}
+
+impl FromStr for Algorithm {
+    type Err = String;
+
+    /// Accepte `nom`, `quick:pivot` (par exemple `quick:median3`) ou, pour le
+    /// tri de Shell, `shell:suite` (par exemple `shell:knuth`).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim().to_lowercase();
+        if let Some(pivot) = s.strip_prefix("quick:") {
+            return Ok(Algorithm::Quick(pivot.parse()?));
+        }
+        if let Some(gaps) = s.strip_prefix("shell:") {
+            return Ok(Algorithm::Shell(gaps.parse()?));
+        }
+        Algorithm::ALL
+            .into_iter()
+            .find(|a| a.name() == s)
+            .ok_or_else(|| format!("Algorithme inconnu : '{}'", s))
+    }
+}
@@ -0,0 +1,125 @@
+use std::time::{Duration, Instant};
+
+use crate::algorithm::Algorithm;
+use crate::generator::Distribution;
+use crate::stats::SortStats;
+
+/// Paramètres d'une campagne de mesure.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BenchConfig {
+    pub algorithms: Vec<Algorithm>,
+    pub distributions: Vec<Distribution>,
+    pub size: usize,
+    pub runs: usize,
+    pub seed: u64,
+    /// Compte aussi les opérations, par une exécution instrumentée en plus
+    /// des mesures de temps ; sinon les compteurs des résultats restent à zéro.
+    pub counters: bool,
+}
+
+/// Temps mesurés pour un algorithme sur `runs` exécutions.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BenchResult {
+    pub algorithm: Algorithm,
+    pub distribution: Distribution,
+    pub size: usize,
+    /// Graine ayant servi à générer l'entrée.
+    pub seed: u64,
+    pub runs: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+    pub stats: SortStats,
+}
+
+/// Lance chaque algorithme `runs` fois sur chaque distribution.
+///
+/// Pour une distribution donnée, tous les algorithmes trient une copie
+/// de la même entrée.
+pub fn run(config: &BenchConfig) -> Vec<BenchResult> {
+    let mut results = Vec::new();
+    for &distribution in &config.distributions {
+        let input = distribution.generate(config.size, config.seed);
+        for &algorithm in &config.algorithms {
+            results.push(measure(algorithm, distribution, &input, config));
+        }
+    }
+    results
+}
+
+fn measure(algorithm: Algorithm, distribution: Distribution, input: &[i32], config: &BenchConfig) -> BenchResult {
+    let mut times: Vec<Duration> = (0..config.runs.max(1))
+        .map(|_| {
+            let mut arr = input.to_vec();
+            let start = Instant::now();
+            algorithm.sort(&mut arr);
+            start.elapsed()
+        })
+        .collect();
+    times.sort();
+
+    // Les compteurs sont déterministes : une exécution instrumentée suffit.
+    let stats = if config.counters {
+        algorithm.sort_instrumented(&mut input.to_vec())
+    } else {
+        SortStats::default()
+    };
+
+    BenchResult {
+        algorithm,
+        distribution,
+        size: input.len(),
+        seed: config.seed,
+        runs: times.len(),
+        min: times[0],
+        median: times[times.len() / 2],
+        max: times[times.len() - 1],
+        stats,
+    }
+}
+
+/// Met en forme les résultats sous forme de tableau texte.
+pub fn format_table(results: &[BenchResult]) -> String {
+    const BORDER: &str = "+----------------+------------------+------------+-------+--------------+--------------+--------------+--------------+--------------+--------------+\n";
+
+    let mut out = String::new();
+    out.push_str(BORDER);
+    out.push_str("| Algorithme     | Distribution     | Taille     | Runs  | Min          | Médiane      | Max          | Comparaisons | Échanges     | Écritures    |\n");
+    out.push_str(BORDER);
+    for r in results {
+        out.push_str(&format!(
+            "| {: <14} | {: <16} | {: <10} | {: <5} | {: <12} | {: <12} | {: <12} | {: <12} | {: <12} | {: <12} |\n",
+            r.algorithm.to_string(),
+            r.distribution.to_string(),
+            r.size,
+            r.runs,
+            format!("{:?}", r.min),
+            format!("{:?}", r.median),
+            format!("{:?}", r.max),
+            r.stats.comparisons,
+            r.stats.swaps,
+            r.stats.writes,
+        ));
+    }
+    out.push_str(BORDER);
+    out
+}
+
+/// Résume l'accélération de chaque tri parallèle par rapport à sa version
+/// séquentielle, lorsque les deux ont été mesurées sur la même distribution.
+pub fn format_speedups(results: &[BenchResult]) -> String {
+    let mut out = String::new();
+    for parallel in results.iter().filter(|r| r.algorithm.sequential() != r.algorithm) {
+        let sequential = results
+            .iter()
+            .find(|r| r.algorithm == parallel.algorithm.sequential() && r.distribution == parallel.distribution);
+        if let Some(sequential) = sequential {
+            let speedup = sequential.median.as_secs_f64() / parallel.median.as_secs_f64().max(f64::MIN_POSITIVE);
+            out.push_str(&format!(
+                "Accélération {} / {} ({}) : x{:.2}\n",
+                parallel.algorithm, sequential.algorithm, parallel.distribution, speedup
+            ));
+        }
+    }
+    out
+}
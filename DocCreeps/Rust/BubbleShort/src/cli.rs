@@ -0,0 +1,416 @@
+use std::str::FromStr;
+
+use std::time::Duration;
+
+use bubble_short::complexity::{self, ComplexityConfig, Metric};
+use bubble_short::{
+    external, Algorithm, BenchConfig, Distribution, ExternalConfig, FileSortConfig, InputFormat, Parallelism, ReportFormat,
+};
+
+use crate::visualizer::VisualizerConfig;
+
+/// Taille au-delà de laquelle le benchmark écarte par défaut les tris
+/// quadratiques, qui demanderaient des minutes ; `--algo` les réintègre.
+pub const QUADRATIC_SIZE_LIMIT: usize = 10_000;
+
+pub const USAGE: &str = "\
+Utilisation :
+  BubbleShort                       Menu interactif
+  BubbleShort [bench] [options]     Mode benchmark non interactif
+  BubbleShort visualize [options]   Animation des tris dans le terminal
+  BubbleShort complexity [options]  Mesure chaque algorithme sur des tailles
+                                    croissantes et estime sa complexité
+  BubbleShort compare <avant> <après> [--threshold <%>]
+                                    Compare deux rapports de benchmark (csv
+                                    ou json) et signale les régressions
+  BubbleShort sort [options] [fichier]
+                                    Trie les lignes d'un fichier (ou de l'entrée
+                                    standard si absent ou '-')
+
+Options :
+  --algo <noms>   Algorithmes séparés par des virgules, ou 'all' (défaut : all,
+                  sans bubble, selection et insertion au-delà de 10000
+                  éléments ; les nommer ou passer 'all' les inclut)
+                  Noms : bubble, selection, insertion, merge,
+                  quick[:first|middle|random|median3|ninther], heap,
+                  shell[:shell|knuth|hibbard|ciura], counting, radix, tim,
+                  par-merge, par-quick
+  --dist <noms>   Distributions d'entrée séparées par des virgules, ou 'all'
+                  (défaut : random). Noms : random, sorted, reversed,
+                  nearly-sorted[:k], few-unique[:k], organ-pipe, sawtooth[:k],
+                  all-equal. Une seule distribution pour visualize.
+  --size <n>      Nombre d'éléments à trier (défaut : 1000, 32 pour visualize)
+  --runs <n>      Nombre d'exécutions par algorithme (défaut : 5)
+  --seed <n>      Graine du générateur aléatoire (défaut : aléatoire)
+  --threads <n>   Threads des tris parallèles (défaut : 0 = un par cœur)
+  --cutoff <n>    Taille en dessous de laquelle les tris parallèles passent
+                  en séquentiel (défaut : 4096)
+  --no-counters   Ne compte pas les comparaisons, échanges et écritures
+                  (évite une exécution instrumentée par algorithme)
+  -o, --output <fichier>
+                  Exporte les résultats du benchmark, répétable ; le format
+                  est déduit de l'extension (.csv, .json, .md)
+  --format <fmt>  Format des exports sans extension reconnue, ou du rapport
+                  affiché sur stdout sans --output : csv, json ou md
+  --threshold <%> Hausse du temps médian ou des comparaisons au-delà de
+                  laquelle compare signale une régression (défaut : 5)
+  -h, --help      Affiche cette aide
+
+Options de complexity (en plus de --algo, --dist, --runs, --seed) :
+  --from <k>            Plus petite taille : 2^k (défaut : 8)
+  --to <k>              Plus grande taille : 2^k (défaut : 14)
+  --metric <m>          Grandeur ajustée : time ou comparisons (défaut : time)
+  --time-limit <ms>     Arrête un algorithme après une taille plus lente que
+                        cette durée (défaut : 1000)
+
+Options de sort :
+  --algo <nom>          Algorithme comparatif à utiliser (défaut : merge)
+  -k, --key <clé>       Colonne de tri, répétable, par priorité décroissante :
+                        numéro de colonne suivi de 'n' (numérique) et/ou
+                        'r' (décroissant). Exemple : -k 3n -k 1r
+  -t, --delimiter <c>   Séparateur de colonnes (défaut : blancs, ',' en CSV)
+  --csv                 Entrée CSV (champs entre guillemets acceptés)
+  --header              Conserve la première ligne en tête
+  -n, --numeric         Compare la ligne entière numériquement (sans -k)
+  -r, --reverse         Inverse l'ordre final
+  -o, --output <fich.>  Écrit le résultat dans un fichier plutôt que sur stdout
+  --external            Tri externe : suites triées sur disque puis fusionnées,
+                        pour les fichiers plus grands que la mémoire
+  --memory <taille>     Mémoire par suite en tri externe, suffixes K, M, G
+                        acceptés (défaut : 64M ; implique --external)
+  --temp-dir <rép.>     Répertoire des fichiers temporaires (défaut : celui
+                        du système ; implique --external)
+  --fan-in <n>          Suites fusionnées à la fois (défaut : 16 ; implique
+                        --external)";
+
+/// Action demandée sur la ligne de commande.
+#[derive(Debug)]
+pub enum Command {
+    Menu,
+    Help,
+    Bench(BenchCommand),
+    Compare(CompareCommand),
+    Complexity(ComplexityConfig),
+    Visualize(VisualizerConfig),
+    Sort(SortCommand),
+}
+
+/// Benchmark : configuration et rapports à produire.
+#[derive(Debug)]
+pub struct BenchCommand {
+    pub config: BenchConfig,
+    /// Fichiers de rapport à écrire, avec leur format.
+    pub reports: Vec<(String, ReportFormat)>,
+    /// Rapport affiché sur la sortie standard à la place du tableau.
+    pub format: Option<ReportFormat>,
+}
+
+/// Comparaison de deux rapports de benchmark.
+#[derive(Debug)]
+pub struct CompareCommand {
+    pub baseline: String,
+    pub current: String,
+    /// Seuil de régression, en pourcentage.
+    pub threshold: f64,
+}
+
+/// Tri d'un fichier : configuration et emplacements d'entrée/sortie.
+#[derive(Debug)]
+pub struct SortCommand {
+    pub config: FileSortConfig,
+    /// Fichier d'entrée ; `None` pour l'entrée standard.
+    pub input: Option<String>,
+    /// Fichier de sortie ; `None` pour la sortie standard.
+    pub output: Option<String>,
+    /// Réglages du tri externe ; `None` pour un tri en mémoire.
+    pub external: Option<ExternalConfig>,
+}
+
+/// Analyse les arguments (sans le nom du programme).
+pub fn parse_args(args: &[String]) -> Result<Command, String> {
+    let Some(first) = args.first() else {
+        return Ok(Command::Menu);
+    };
+
+    match first.as_str() {
+        "-h" | "--help" => Ok(Command::Help),
+        "bench" => parse_bench(&args[1..]),
+        "visualize" => parse_visualize(&args[1..]),
+        "compare" => parse_compare(&args[1..]),
+        "complexity" => parse_complexity(&args[1..]),
+        "sort" => parse_sort(&args[1..]),
+        option if option.starts_with('-') => parse_bench(args),
+        other => Err(format!("Commande inconnue : '{}'", other)),
+    }
+}
+
+fn parse_bench(args: &[String]) -> Result<Command, String> {
+    let mut config = BenchConfig {
+        algorithms: Algorithm::ALL.to_vec(),
+        distributions: vec![Distribution::Random],
+        size: 1000,
+        runs: 5,
+        seed: rand::random(),
+        counters: true,
+    };
+    let mut algorithms: Vec<Algorithm> = Vec::new();
+    let mut distributions: Vec<Distribution> = Vec::new();
+    let mut parallelism = Parallelism::default();
+    let mut outputs: Vec<String> = Vec::new();
+    let mut format: Option<ReportFormat> = None;
+
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "-h" | "--help" => return Ok(Command::Help),
+            "--algo" => algorithms.extend(parse_list(value(&mut iter, arg)?, &Algorithm::ALL)?),
+            "--dist" => distributions.extend(parse_list(value(&mut iter, arg)?, &Distribution::ALL)?),
+            "--size" => config.size = parse_number(value(&mut iter, arg)?, arg)?,
+            "--runs" => config.runs = parse_number(value(&mut iter, arg)?, arg)?,
+            "--seed" => config.seed = parse_number(value(&mut iter, arg)?, arg)?,
+            "--threads" => parallelism.threads = parse_number(value(&mut iter, arg)?, arg)?,
+            "--cutoff" => parallelism.cutoff = parse_number(value(&mut iter, arg)?, arg)?,
+            "--no-counters" => config.counters = false,
+            "-o" | "--output" => outputs.push(value(&mut iter, arg)?.to_string()),
+            "--format" => format = Some(value(&mut iter, arg)?.parse()?),
+            other => return Err(format!("Option inconnue : '{}'", other)),
+        }
+    }
+
+    if config.runs == 0 {
+        return Err("--runs doit être au moins 1".to_string());
+    }
+    if !algorithms.is_empty() {
+        config.algorithms = dedup(algorithms);
+    } else if config.size > QUADRATIC_SIZE_LIMIT {
+        config.algorithms.retain(|algorithm| !algorithm.is_quadratic());
+    }
+    for algorithm in &mut config.algorithms {
+        *algorithm = algorithm.with_parallelism(parallelism);
+    }
+    if !distributions.is_empty() {
+        config.distributions = dedup(distributions);
+    }
+
+    let reports = outputs
+        .into_iter()
+        .map(|path| {
+            let report_format = ReportFormat::from_path(path.as_ref())
+                .or(format)
+                .ok_or_else(|| format!("Format de rapport inconnu pour '{}' : précisez --format", path))?;
+            Ok((path, report_format))
+        })
+        .collect::<Result<Vec<_>, String>>()?;
+    let format = if reports.is_empty() { format } else { None };
+
+    Ok(Command::Bench(BenchCommand { config, reports, format }))
+}
+
+fn parse_compare(args: &[String]) -> Result<Command, String> {
+    let mut files: Vec<String> = Vec::new();
+    let mut threshold: f64 = 5.0;
+
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "-h" | "--help" => return Ok(Command::Help),
+            "--threshold" => threshold = parse_number(value(&mut iter, arg)?, arg)?,
+            option if option.starts_with('-') => return Err(format!("Option inconnue : '{}'", option)),
+            path => files.push(path.to_string()),
+        }
+    }
+
+    if threshold.is_nan() || threshold < 0.0 {
+        return Err("--threshold doit être un pourcentage positif".to_string());
+    }
+    let [baseline, current] = <[String; 2]>::try_from(files)
+        .map_err(|_| "compare attend exactement deux rapports : <avant> <après>".to_string())?;
+    Ok(Command::Compare(CompareCommand { baseline, current, threshold }))
+}
+
+fn parse_complexity(args: &[String]) -> Result<Command, String> {
+    let mut config = ComplexityConfig {
+        algorithms: Algorithm::ALL.to_vec(),
+        distribution: Distribution::Random,
+        sizes: Vec::new(),
+        runs: 3,
+        seed: rand::random(),
+        metric: Metric::Time,
+        time_limit: Duration::from_millis(1000),
+    };
+    let mut algorithms: Vec<Algorithm> = Vec::new();
+    let (mut from, mut to) = (8u32, 14u32);
+
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "-h" | "--help" => return Ok(Command::Help),
+            "--algo" => algorithms.extend(parse_list(value(&mut iter, arg)?, &Algorithm::ALL)?),
+            "--dist" => config.distribution = value(&mut iter, arg)?.parse()?,
+            "--from" => from = parse_number(value(&mut iter, arg)?, arg)?,
+            "--to" => to = parse_number(value(&mut iter, arg)?, arg)?,
+            "--runs" => config.runs = parse_number(value(&mut iter, arg)?, arg)?,
+            "--seed" => config.seed = parse_number(value(&mut iter, arg)?, arg)?,
+            "--metric" => config.metric = value(&mut iter, arg)?.parse()?,
+            "--time-limit" => config.time_limit = Duration::from_millis(parse_number(value(&mut iter, arg)?, arg)?),
+            other => return Err(format!("Option inconnue : '{}'", other)),
+        }
+    }
+
+    if config.runs == 0 {
+        return Err("--runs doit être au moins 1".to_string());
+    }
+    if from >= to || to > 30 {
+        return Err(format!("Tailles invalides : 2^{}..2^{} (il faut --from < --to <= 30)", from, to));
+    }
+    config.sizes = complexity::powers_of_two(from, to);
+    if !algorithms.is_empty() {
+        config.algorithms = dedup(algorithms);
+    }
+
+    Ok(Command::Complexity(config))
+}
+
+fn parse_visualize(args: &[String]) -> Result<Command, String> {
+    let mut config = VisualizerConfig {
+        algorithms: Algorithm::ALL.to_vec(),
+        distribution: Distribution::Random,
+        size: 32,
+        seed: rand::random(),
+    };
+    let mut algorithms: Vec<Algorithm> = Vec::new();
+
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "-h" | "--help" => return Ok(Command::Help),
+            "--algo" => algorithms.extend(parse_list(value(&mut iter, arg)?, &Algorithm::ALL)?),
+            "--dist" => config.distribution = value(&mut iter, arg)?.parse()?,
+            "--size" => config.size = parse_number(value(&mut iter, arg)?, arg)?,
+            "--seed" => config.seed = parse_number(value(&mut iter, arg)?, arg)?,
+            other => return Err(format!("Option inconnue : '{}'", other)),
+        }
+    }
+
+    if !algorithms.is_empty() {
+        config.algorithms = dedup(algorithms);
+    }
+
+    Ok(Command::Visualize(config))
+}
+
+fn parse_sort(args: &[String]) -> Result<Command, String> {
+    let mut config = FileSortConfig {
+        algorithm: Algorithm::Merge,
+        format: InputFormat::Lines { delimiter: None },
+        keys: Vec::new(),
+        numeric: false,
+        reverse: false,
+        header: false,
+    };
+    let mut csv = false;
+    let mut delimiter: Option<char> = None;
+    let mut input: Option<String> = None;
+    let mut output: Option<String> = None;
+    let mut external: Option<ExternalConfig> = None;
+
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "-h" | "--help" => return Ok(Command::Help),
+            "--algo" => config.algorithm = value(&mut iter, arg)?.parse()?,
+            "-k" | "--key" => config.keys.push(value(&mut iter, arg)?.parse()?),
+            "-t" | "--delimiter" => {
+                let text = value(&mut iter, arg)?;
+                let mut chars = text.chars();
+                match (chars.next(), chars.next()) {
+                    (Some(c), None) => delimiter = Some(c),
+                    _ => return Err(format!("Le séparateur doit être un seul caractère : '{}'", text)),
+                }
+            }
+            "--csv" => csv = true,
+            "--header" => config.header = true,
+            "-n" | "--numeric" => config.numeric = true,
+            "-r" | "--reverse" => config.reverse = true,
+            "-o" | "--output" => output = Some(value(&mut iter, arg)?.to_string()),
+            "--external" => {
+                external.get_or_insert_with(ExternalConfig::default);
+            }
+            "--memory" => {
+                external.get_or_insert_with(ExternalConfig::default).memory_limit =
+                    external::parse_memory(value(&mut iter, arg)?)?
+            }
+            "--temp-dir" => external.get_or_insert_with(ExternalConfig::default).temp_dir = value(&mut iter, arg)?.into(),
+            "--fan-in" => {
+                let fan_in: usize = parse_number(value(&mut iter, arg)?, arg)?;
+                if fan_in < 2 {
+                    return Err("--fan-in doit valoir au moins 2".to_string());
+                }
+                external.get_or_insert_with(ExternalConfig::default).fan_in = fan_in;
+            }
+            "-" => input = None,
+            option if option.starts_with('-') => return Err(format!("Option inconnue : '{}'", option)),
+            path => {
+                if input.is_some() {
+                    return Err(format!("Un seul fichier d'entrée est accepté : '{}'", path));
+                }
+                input = Some(path.to_string());
+            }
+        }
+    }
+
+    if !config.algorithm.is_comparison() {
+        return Err(format!("{} ne peut pas trier des lignes de texte", config.algorithm.label()));
+    }
+    config.format = if csv {
+        InputFormat::Csv { delimiter: delimiter.unwrap_or(',') }
+    } else {
+        InputFormat::Lines { delimiter }
+    };
+
+    Ok(Command::Sort(SortCommand { config, input, output, external }))
+}
+
+/// Analyse une liste séparée par des virgules, où `all` désigne toutes les valeurs.
+fn parse_list<T>(value: &str, all: &[T]) -> Result<Vec<T>, String>
+where
+    T: FromStr<Err = String> + Copy,
+{
+    let mut items = Vec::new();
+    for name in value.split(',') {
+        if name.trim() == "all" {
+            items.extend_from_slice(all);
+        } else {
+            items.push(name.parse()?);
+        }
+    }
+    Ok(items)
+}
+
+/// Retire les doublons en conservant l'ordre de première apparition.
+fn dedup<T: PartialEq + Copy>(mut items: Vec<T>) -> Vec<T> {
+    let mut seen = Vec::new();
+    items.retain(|item| {
+        let first = !seen.contains(item);
+        seen.push(*item);
+        first
+    });
+    items
+}
+
+fn value<'a>(iter: &mut impl Iterator<Item = &'a String>, option: &str) -> Result<&'a str, String> {
+    iter.next()
+        .map(String::as_str)
+        .ok_or_else(|| format!("Valeur manquante pour {}", option))
+}
+
+fn parse_number<N: FromStr>(value: &str, option: &str) -> Result<N, String> {
+    value
+        .parse()
+        .map_err(|_| format!("Valeur invalide pour {} : '{}'", option, value))
+}
+
+// Déclaration du module de tests
+#[cfg(test)]
+#[path = "tests/cli.rs"]
+mod tests;
@@ -0,0 +1,315 @@
+use std::fmt;
+use std::str::FromStr;
+use std::time::Duration;
+
+use crate::algorithm::Algorithm;
+use crate::bench::{self, BenchConfig, BenchResult};
+use crate::generator::Distribution;
+
+/// Classe de complexité candidate : le coût est modélisé par `c · f(n)`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ComplexityClass {
+    Linear,
+    Linearithmic,
+    Quadratic,
+}
+
+impl ComplexityClass {
+    pub const ALL: [ComplexityClass; 3] = [
+        ComplexityClass::Linear,
+        ComplexityClass::Linearithmic,
+        ComplexityClass::Quadratic,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            ComplexityClass::Linear => "O(n)",
+            ComplexityClass::Linearithmic => "O(n log n)",
+            ComplexityClass::Quadratic => "O(n²)",
+        }
+    }
+
+    /// Valeur de `f(n)`.
+    pub fn eval(self, n: usize) -> f64 {
+        let n = n as f64;
+        match self {
+            ComplexityClass::Linear => n,
+            ComplexityClass::Linearithmic => n * n.max(2.0).log2(),
+            ComplexityClass::Quadratic => n * n,
+        }
+    }
+}
+
+impl fmt::Display for ComplexityClass {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.label())
+    }
+}
+
+/// Grandeur mesurée pour chaque taille.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Metric {
+    /// Temps médian : dépend de la machine, bruité pour les petites tailles.
+    #[default]
+    Time,
+    /// Nombre de comparaisons : exact et reproductible, mais nul pour les
+    /// tris non comparatifs.
+    Comparisons,
+}
+
+impl Metric {
+    pub fn name(self) -> &'static str {
+        match self {
+            Metric::Time => "time",
+            Metric::Comparisons => "comparisons",
+        }
+    }
+
+    /// Valeur de la grandeur pour un résultat de benchmark.
+    pub fn value(self, result: &BenchResult) -> f64 {
+        match self {
+            Metric::Time => result.median.as_nanos() as f64,
+            Metric::Comparisons => result.stats.comparisons as f64,
+        }
+    }
+
+    /// Met en forme une valeur de la grandeur pour l'affichage.
+    pub fn format(self, value: f64) -> String {
+        match self {
+            Metric::Time => format!("{:?}", Duration::from_nanos(value as u64)),
+            Metric::Comparisons => format!("{:.0}", value),
+        }
+    }
+}
+
+impl fmt::Display for Metric {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for Metric {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "time" => Ok(Metric::Time),
+            "comparisons" => Ok(Metric::Comparisons),
+            _ => Err(format!("Grandeur inconnue : '{}' (time ou comparisons)", s)),
+        }
+    }
+}
+
+/// Paramètres d'un balayage de tailles.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ComplexityConfig {
+    pub algorithms: Vec<Algorithm>,
+    pub distribution: Distribution,
+    /// Tailles mesurées, par ordre croissant.
+    pub sizes: Vec<usize>,
+    pub runs: usize,
+    pub seed: u64,
+    pub metric: Metric,
+    /// Un algorithme dont le temps médian dépasse cette durée n'est pas
+    /// mesuré sur les tailles suivantes (utile pour les tris quadratiques).
+    pub time_limit: Duration,
+}
+
+/// Tailles `2^from`, `2^(from+1)`, ..., `2^to`.
+pub fn powers_of_two(from: u32, to: u32) -> Vec<usize> {
+    (from..=to).map(|exponent| 1usize << exponent).collect()
+}
+
+/// Ajustement des mesures à une classe de complexité.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Fit {
+    pub class: ComplexityClass,
+    /// Constante `c` du modèle `c · f(n)`.
+    pub coefficient: f64,
+    /// Erreur relative quadratique moyenne du modèle (0 = parfait).
+    pub error: f64,
+}
+
+/// Ajuste les points `(n, y)` au modèle `c · f(n)` en minimisant l'erreur
+/// relative, pour que les grandes tailles n'écrasent pas les petites.
+///
+/// Renvoie `None` s'il y a moins de deux points ou une mesure nulle.
+pub fn fit(points: &[(usize, f64)], class: ComplexityClass) -> Option<Fit> {
+    if points.len() < 2 || points.iter().any(|&(_, y)| y <= 0.0) {
+        return None;
+    }
+
+    // Minimum de Σ ((c·f - y) / y)² : c = Σ (f/y) / Σ (f/y)²
+    let ratios: Vec<f64> = points.iter().map(|&(n, y)| class.eval(n) / y).collect();
+    let coefficient = ratios.iter().sum::<f64>() / ratios.iter().map(|r| r * r).sum::<f64>();
+    let error = (ratios.iter().map(|r| (coefficient * r - 1.0).powi(2)).sum::<f64>() / ratios.len() as f64).sqrt();
+    Some(Fit { class, coefficient, error })
+}
+
+/// Mesures d'un algorithme sur toutes les tailles, et ajustements obtenus.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ComplexityResult {
+    pub algorithm: Algorithm,
+    pub metric: Metric,
+    pub samples: Vec<BenchResult>,
+    /// Ajustements classés du meilleur au moins bon.
+    pub fits: Vec<Fit>,
+}
+
+impl ComplexityResult {
+    fn new(algorithm: Algorithm, metric: Metric, samples: Vec<BenchResult>) -> Self {
+        let mut result = ComplexityResult { algorithm, metric, samples, fits: Vec::new() };
+        let points = result.points();
+        result.fits = ComplexityClass::ALL.iter().filter_map(|&class| fit(&points, class)).collect();
+        result.fits.sort_by(|a, b| a.error.total_cmp(&b.error));
+        result
+    }
+
+    /// Points `(n, grandeur mesurée)`.
+    pub fn points(&self) -> Vec<(usize, f64)> {
+        self.samples.iter().map(|s| (s.size, self.metric.value(s))).collect()
+    }
+
+    /// Classe qui explique le mieux les mesures.
+    pub fn best(&self) -> Option<Fit> {
+        self.fits.first().copied()
+    }
+}
+
+/// Mesure chaque algorithme sur chaque taille puis ajuste les courbes.
+pub fn sweep(config: &ComplexityConfig) -> Vec<ComplexityResult> {
+    config
+        .algorithms
+        .iter()
+        .map(|&algorithm| {
+            let mut samples = Vec::new();
+            for &size in &config.sizes {
+                let bench_config = BenchConfig {
+                    algorithms: vec![algorithm],
+                    distributions: vec![config.distribution],
+                    size,
+                    runs: config.runs,
+                    seed: config.seed,
+                    counters: config.metric == Metric::Comparisons,
+                };
+                let sample = bench::run(&bench_config).remove(0);
+                let too_slow = sample.median > config.time_limit;
+                samples.push(sample);
+                if too_slow {
+                    break;
+                }
+            }
+            ComplexityResult::new(algorithm, config.metric, samples)
+        })
+        .collect()
+}
+
+/// Tableau des meilleurs ajustements, avec l'erreur de chaque modèle.
+pub fn format_fits(results: &[ComplexityResult]) -> String {
+    const BORDER: &str = "+----------------+------------+--------------+--------------+--------------+--------------+\n";
+
+    let mut out = String::new();
+    out.push_str(BORDER);
+    out.push_str("| Algorithme     | Tailles    | Meilleure    | Erreur O(n)  | Err. n log n | Erreur O(n²) |\n");
+    out.push_str(BORDER);
+    for result in results {
+        let error = |class: ComplexityClass| {
+            result
+                .fits
+                .iter()
+                .find(|f| f.class == class)
+                .map_or("-".to_string(), |f| format!("{:.1} %", f.error * 100.0))
+        };
+        let sizes = match (result.samples.first(), result.samples.last()) {
+            (Some(first), Some(last)) => format!("{}..{}", first.size, last.size),
+            _ => "-".to_string(),
+        };
+        out.push_str(&format!(
+            "| {: <14} | {: <10} | {: <12} | {: <12} | {: <12} | {: <12} |\n",
+            result.algorithm.to_string(),
+            sizes,
+            result.best().map_or("indéterminée", |f| f.class.label()),
+            error(ComplexityClass::Linear),
+            error(ComplexityClass::Linearithmic),
+            error(ComplexityClass::Quadratic),
+        ));
+    }
+    out.push_str(BORDER);
+    out
+}
+
+/// Symboles des courbes, dans l'ordre des algorithmes.
+const SYMBOLS: &[char] = &['*', 'o', '+', 'x', '#', '@', '%', '&', '=', '~', '$', '^'];
+
+/// Trace la grandeur mesurée en fonction de n, en échelles logarithmiques :
+/// une droite de pente 1 correspond à O(n), de pente 2 à O(n²).
+pub fn plot(results: &[ComplexityResult], width: usize, height: usize) -> String {
+    let (width, height) = (width.max(10), height.max(4));
+    let series: Vec<Vec<(f64, f64)>> = results
+        .iter()
+        .map(|r| {
+            r.points()
+                .into_iter()
+                .filter(|&(_, y)| y > 0.0)
+                .map(|(n, y)| ((n as f64).log2(), y.log10()))
+                .collect()
+        })
+        .collect();
+
+    let all = || series.iter().flatten();
+    let Some(metric) = results.first().map(|r| r.metric) else {
+        return String::new();
+    };
+    if all().next().is_none() {
+        return "Aucune mesure non nulle à tracer\n".to_string();
+    }
+    let bounds = |values: Vec<f64>| {
+        let min = values.iter().copied().fold(f64::INFINITY, f64::min);
+        let max = values.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+        (min, if max > min { max } else { min + 1.0 })
+    };
+    let (x_min, x_max) = bounds(all().map(|p| p.0).collect());
+    let (y_min, y_max) = bounds(all().map(|p| p.1).collect());
+
+    let mut grid = vec![vec![' '; width]; height];
+    for (points, symbol) in series.iter().zip(SYMBOLS.iter().cycle()) {
+        for &(x, y) in points {
+            let column = ((x - x_min) / (x_max - x_min) * (width - 1) as f64).round() as usize;
+            let row = ((y_max - y) / (y_max - y_min) * (height - 1) as f64).round() as usize;
+            grid[row][column] = *symbol;
+        }
+    }
+
+    let top = metric.format(10f64.powf(y_max));
+    let bottom = metric.format(10f64.powf(y_min));
+    let margin = top.len().max(bottom.len());
+
+    let mut out = String::new();
+    for (i, row) in grid.iter().enumerate() {
+        let label = match i {
+            0 => top.as_str(),
+            i if i == height - 1 => bottom.as_str(),
+            _ => "",
+        };
+        out.push_str(&format!("{: >margin$} |{}\n", label, row.iter().collect::<String>()));
+    }
+    out.push_str(&format!("{: >margin$} +{}\n", "", "-".repeat(width)));
+    let left = format!("n = {}", 2f64.powf(x_min).round());
+    let right = format!("{}", 2f64.powf(x_max).round());
+    out.push_str(&format!(
+        "{: >margin$}  {}{: >pad$}\n",
+        "",
+        left,
+        right,
+        pad = width.saturating_sub(left.len())
+    ));
+    for (result, symbol) in results.iter().zip(SYMBOLS.iter().cycle()) {
+        out.push_str(&format!(
+            "  {} {} ({})\n",
+            symbol,
+            result.algorithm,
+            result.best().map_or("indéterminée", |f| f.class.label())
+        ));
+    }
+    out
+}
@@ -0,0 +1,231 @@
+use std::cmp::{Ordering, Reverse};
+use std::collections::BinaryHeap;
+use std::fs::{self, File};
+use std::io::{self, BufRead, BufReader, BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::records::{compare_records, FileSortConfig, Record, RecordReader};
+
+/// Paramètres du tri externe.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExternalConfig {
+    /// Mémoire approximative consacrée à une suite triée en RAM, en octets.
+    pub memory_limit: usize,
+    /// Nombre maximal de suites fusionnées simultanément (fichiers ouverts).
+    pub fan_in: usize,
+    /// Répertoire des fichiers temporaires.
+    pub temp_dir: PathBuf,
+}
+
+impl Default for ExternalConfig {
+    fn default() -> Self {
+        Self {
+            memory_limit: 64 * 1024 * 1024,
+            fan_in: 16,
+            temp_dir: std::env::temp_dir(),
+        }
+    }
+}
+
+/// Bilan d'un tri externe.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ExternalStats {
+    /// Nombre d'enregistrements triés (en-tête exclu).
+    pub records: usize,
+    /// Nombre de suites triées écrites sur disque lors du découpage.
+    pub runs: usize,
+    /// Nombre de passes de fusion intermédiaires (hors fusion finale).
+    pub merge_passes: usize,
+}
+
+/// Analyse une taille mémoire : `512`, `64K`, `64M`, `2G`.
+pub fn parse_memory(text: &str) -> Result<usize, String> {
+    let text = text.trim();
+    let (digits, multiplier) = match text.chars().last().map(|c| c.to_ascii_uppercase()) {
+        Some('K') => (&text[..text.len() - 1], 1024),
+        Some('M') => (&text[..text.len() - 1], 1024 * 1024),
+        Some('G') => (&text[..text.len() - 1], 1024 * 1024 * 1024),
+        _ => (text, 1),
+    };
+    digits
+        .parse::<usize>()
+        .ok()
+        .and_then(|n| n.checked_mul(multiplier))
+        .filter(|&n| n > 0)
+        .ok_or_else(|| format!("Taille mémoire invalide : '{}' (exemple : 64M)", text))
+}
+
+/// Répertoire temporaire supprimé avec son contenu à la fin du tri,
+/// y compris en cas d'erreur.
+struct TempDir {
+    path: PathBuf,
+    next_run: usize,
+}
+
+impl TempDir {
+    fn new(parent: &Path) -> io::Result<Self> {
+        let nanos = SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |d| d.as_nanos());
+        let path = parent.join(format!("bubbleshort-{}-{}", std::process::id(), nanos));
+        fs::create_dir_all(&path)?;
+        Ok(Self { path, next_run: 0 })
+    }
+
+    fn new_run_path(&mut self) -> PathBuf {
+        self.next_run += 1;
+        self.path.join(format!("run-{:06}.txt", self.next_run))
+    }
+}
+
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.path);
+    }
+}
+
+/// Trie un flux trop grand pour la mémoire :
+/// 1. découpage en suites d'au plus `memory_limit` octets, triées en RAM avec
+///    l'algorithme choisi puis écrites sur disque ;
+/// 2. fusions successives de `fan_in` suites au plus, jusqu'à la sortie.
+///
+/// À clés égales, l'ordre d'entrée est conservé si l'algorithme est stable.
+pub fn external_sort(
+    reader: impl BufRead,
+    mut writer: impl Write,
+    config: &FileSortConfig,
+    external: &ExternalConfig,
+) -> Result<ExternalStats, String> {
+    if !config.algorithm.is_comparison() {
+        return Err(format!(
+            "{} ne sait trier que des entiers : choisissez un tri comparatif",
+            config.algorithm.label()
+        ));
+    }
+    let io_error = |context: &str, e: io::Error| format!("{} : {}", context, e);
+
+    let mut temp = TempDir::new(&external.temp_dir)
+        .map_err(|e| io_error("Impossible de créer le répertoire temporaire", e))?;
+    let mut stats = ExternalStats::default();
+    let mut records = RecordReader::new(reader, config.format);
+
+    let header = if config.header {
+        records.next().transpose().map_err(|e| io_error("Erreur de lecture", e))?
+    } else {
+        None
+    };
+
+    // Phase 1 : suites triées en mémoire
+    let mut runs: Vec<PathBuf> = Vec::new();
+    let mut chunk: Vec<Record> = Vec::new();
+    let mut chunk_size = 0;
+    loop {
+        let record = records.next().transpose().map_err(|e| io_error("Erreur de lecture", e))?;
+        let end = record.is_none();
+        if let Some(record) = record {
+            chunk_size += record.memory_size();
+            chunk.push(record);
+            stats.records += 1;
+        }
+        if (end && !chunk.is_empty()) || chunk_size >= external.memory_limit {
+            config.algorithm.sort_by(&mut chunk, |a, b| compare_records(a, b, config))?;
+            let path = temp.new_run_path();
+            write_run(&path, &chunk).map_err(|e| io_error("Erreur d'écriture d'une suite", e))?;
+            runs.push(path);
+            chunk.clear();
+            chunk_size = 0;
+        }
+        if end {
+            break;
+        }
+    }
+    stats.runs = runs.len();
+
+    // Phase 2 : fusions intermédiaires tant qu'il y a trop de suites
+    let fan_in = external.fan_in.max(2);
+    while runs.len() > fan_in {
+        let mut merged = Vec::new();
+        for group in runs.chunks(fan_in) {
+            let path = temp.new_run_path();
+            let file = File::create(&path).map_err(|e| io_error("Erreur d'écriture d'une suite", e))?;
+            merge_runs(group, BufWriter::new(file), config)?;
+            for run in group {
+                let _ = fs::remove_file(run);
+            }
+            merged.push(path);
+        }
+        runs = merged;
+        stats.merge_passes += 1;
+    }
+
+    // Fusion finale vers la sortie
+    if let Some(header) = header {
+        writeln!(writer, "{}", header.text).map_err(|e| io_error("Erreur d'écriture", e))?;
+    }
+    merge_runs(&runs, writer, config)?;
+    Ok(stats)
+}
+
+fn write_run(path: &Path, records: &[Record]) -> io::Result<()> {
+    let mut writer = BufWriter::new(File::create(path)?);
+    for record in records {
+        writeln!(writer, "{}", record.text)?;
+    }
+    writer.flush()
+}
+
+/// Enregistrement de tête d'une suite pendant la fusion, ordonné selon la
+/// configuration du tri puis par numéro de suite.
+struct Head<'a> {
+    record: Record,
+    run: usize,
+    config: &'a FileSortConfig,
+}
+
+impl Ord for Head<'_> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        compare_records(&self.record, &other.record, self.config).then(self.run.cmp(&other.run))
+    }
+}
+
+impl PartialOrd for Head<'_> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for Head<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Head<'_> {}
+
+/// Fusion k-voies des suites `runs`, en ne gardant en mémoire que
+/// l'enregistrement de tête de chaque suite : O(N log k) comparaisons.
+fn merge_runs(runs: &[PathBuf], mut writer: impl Write, config: &FileSortConfig) -> Result<(), String> {
+    let read_error = |e: io::Error| format!("Erreur de lecture d'une suite : {}", e);
+    let write_error = |e: io::Error| format!("Erreur d'écriture : {}", e);
+
+    let mut readers = Vec::with_capacity(runs.len());
+    for path in runs {
+        let file = File::open(path).map_err(read_error)?;
+        readers.push(RecordReader::new(BufReader::new(file), config.format));
+    }
+    // Tas minimum des têtes ; à égalité, la suite la plus ancienne gagne :
+    // la fusion reste stable.
+    let mut heads = BinaryHeap::with_capacity(readers.len());
+    for (run, reader) in readers.iter_mut().enumerate() {
+        if let Some(record) = reader.next().transpose().map_err(read_error)? {
+            heads.push(Reverse(Head { record, run, config }));
+        }
+    }
+
+    while let Some(Reverse(head)) = heads.pop() {
+        writeln!(writer, "{}", head.record.text).map_err(write_error)?;
+        if let Some(record) = readers[head.run].next().transpose().map_err(read_error)? {
+            heads.push(Reverse(Head { record, ..head }));
+        }
+    }
+    writer.flush().map_err(write_error)
+}
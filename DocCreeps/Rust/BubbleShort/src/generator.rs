@@ -0,0 +1,134 @@
+use std::fmt;
+use std::str::FromStr;
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+/// Forme des données d'entrée à trier.
+///
+/// Toutes les distributions sont reproductibles : même taille et même graine
+/// donnent toujours le même tableau.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Distribution {
+    /// Valeurs uniformes dans `0..1000` (comportement historique du menu).
+    #[default]
+    Random,
+    /// `0, 1, 2, ..., n-1`.
+    Sorted,
+    /// `n-1, ..., 1, 0`.
+    Reversed,
+    /// Tableau trié sur lequel on applique `swaps` échanges aléatoires.
+    NearlySorted { swaps: usize },
+    /// Valeurs uniformes parmi `distinct` valeurs seulement (nombreux doublons).
+    FewUnique { distinct: usize },
+    /// Montée puis descente : `0, 1, ..., n/2, ..., 1, 0`.
+    OrganPipe,
+    /// `teeth` rampes croissantes successives.
+    Sawtooth { teeth: usize },
+    /// Tous les éléments égaux.
+    AllEqual,
+}
+
+impl Distribution {
+    /// Toutes les distributions, avec leurs paramètres par défaut.
+    pub const ALL: [Distribution; 8] = [
+        Distribution::Random,
+        Distribution::Sorted,
+        Distribution::Reversed,
+        Distribution::NearlySorted { swaps: 10 },
+        Distribution::FewUnique { distinct: 10 },
+        Distribution::OrganPipe,
+        Distribution::Sawtooth { teeth: 4 },
+        Distribution::AllEqual,
+    ];
+
+    /// Nom court utilisé en ligne de commande, sans paramètre.
+    pub fn name(self) -> &'static str {
+        match self {
+            Distribution::Random => "random",
+            Distribution::Sorted => "sorted",
+            Distribution::Reversed => "reversed",
+            Distribution::NearlySorted { .. } => "nearly-sorted",
+            Distribution::FewUnique { .. } => "few-unique",
+            Distribution::OrganPipe => "organ-pipe",
+            Distribution::Sawtooth { .. } => "sawtooth",
+            Distribution::AllEqual => "all-equal",
+        }
+    }
+
+    /// Génère `size` valeurs selon cette distribution.
+    pub fn generate(self, size: usize, seed: u64) -> Vec<i32> {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let n = size as i32;
+
+        match self {
+            Distribution::Random => (0..size).map(|_| rng.gen_range(0..1000)).collect(),
+            Distribution::Sorted => (0..n).collect(),
+            Distribution::Reversed => (0..n).rev().collect(),
+            Distribution::NearlySorted { swaps } => {
+                let mut arr: Vec<i32> = (0..n).collect();
+                if size > 1 {
+                    for _ in 0..swaps {
+                        let i = rng.gen_range(0..size);
+                        let j = rng.gen_range(0..size);
+                        arr.swap(i, j);
+                    }
+                }
+                arr
+            }
+            Distribution::FewUnique { distinct } => {
+                let distinct = distinct.max(1) as i32;
+                (0..size).map(|_| rng.gen_range(0..distinct)).collect()
+            }
+            Distribution::OrganPipe => (0..n).map(|i| i.min(n - 1 - i)).collect(),
+            Distribution::Sawtooth { teeth } => {
+                let period = (size / teeth.max(1)).max(1) as i32;
+                (0..n).map(|i| i % period).collect()
+            }
+            Distribution::AllEqual => vec![42; size],
+        }
+    }
+}
+
+impl fmt::Display for Distribution {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Distribution::NearlySorted { swaps } => write!(f, "{}:{}", self.name(), swaps),
+            Distribution::FewUnique { distinct } => write!(f, "{}:{}", self.name(), distinct),
+            Distribution::Sawtooth { teeth } => write!(f, "{}:{}", self.name(), teeth),
+            _ => f.write_str(self.name()),
+        }
+    }
+}
+
+impl FromStr for Distribution {
+    type Err = String;
+
+    /// Accepte `nom` ou `nom:paramètre`, par exemple `nearly-sorted:50`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim().to_lowercase();
+        let (name, param) = match s.split_once(':') {
+            Some((name, param)) => (name, Some(param)),
+            None => (s.as_str(), None),
+        };
+
+        let base = Distribution::ALL
+            .into_iter()
+            .find(|d| d.name() == name)
+            .ok_or_else(|| format!("Distribution inconnue : '{}'", s))?;
+
+        let Some(param) = param else {
+            return Ok(base);
+        };
+        let value: usize = param
+            .parse()
+            .map_err(|_| format!("Paramètre invalide pour {} : '{}'", name, param))?;
+
+        match base {
+            Distribution::NearlySorted { .. } => Ok(Distribution::NearlySorted { swaps: value }),
+            Distribution::FewUnique { .. } => Ok(Distribution::FewUnique { distinct: value }),
+            Distribution::Sawtooth { .. } => Ok(Distribution::Sawtooth { teeth: value }),
+            _ => Err(format!("La distribution {} n'accepte pas de paramètre", name)),
+        }
+    }
+}
@@ -0,0 +1,67 @@
+//! Algorithmes de tri réutilisables.
+//!
+//! Chaque algorithme existe en trois variantes, sur le modèle de `slice::sort` :
+//! - `xxx_sort(&mut [T])` pour `T: Ord` ;
+//! - `xxx_sort_by(&mut [T], cmp)` avec une fonction de comparaison ;
+//! - `xxx_sort_by_key(&mut [T], key)` avec une fonction d'extraction de clé.
+//!
+//! Les tris non comparatifs (`counting_sort`, `radix_sort`) travaillent sur des
+//! clés entières ([`RadixKey`]) et n'ont donc pas de variante `_by`.
+//!
+//! ```
+//! use bubble_short::{quick_sort, merge_sort_by_key, insertion_sort_by};
+//!
+//! let mut nombres = vec![5, 1, 4];
+//! quick_sort(&mut nombres);
+//! assert_eq!(nombres, [1, 4, 5]);
+//!
+//! let mut mots = vec!["pomme", "kiwi", "banane"];
+//! merge_sort_by_key(&mut mots, |m| m.len());
+//! assert_eq!(mots, ["kiwi", "pomme", "banane"]);
+//!
+//! let mut flottants = vec![2.5, -1.0, 0.3];
+//! insertion_sort_by(&mut flottants, |a: &f64, b: &f64| a.total_cmp(b));
+//! assert_eq!(flottants, [-1.0, 0.3, 2.5]);
+//! ```
+//!
+//! Pour une médiane ou les k plus grands éléments, inutile de tout trier :
+//! [`nth_element`], [`median`], [`partial_sort`] et [`top_k`] s'appuient sur
+//! la même [`partition`] que le tri rapide.
+//!
+//! Chaque algorithme peut aussi être exécuté en mode instrumenté via
+//! [`Algorithm::sort_instrumented`], qui renvoie un [`SortStats`]
+//! (comparaisons, échanges, écritures, profondeur de récursion, allocations).
+
+pub mod algorithm;
+pub mod bench;
+pub mod complexity;
+pub mod external;
+pub mod generator;
+pub mod observer;
+pub mod parallel;
+pub mod radix;
+pub mod records;
+pub mod report;
+pub mod select;
+pub mod sorts;
+pub mod stats;
+pub mod trace;
+
+pub use algorithm::Algorithm;
+pub use bench::{BenchConfig, BenchResult};
+pub use external::{ExternalConfig, ExternalStats};
+pub use generator::Distribution;
+pub use observer::{NoopObserver, SortObserver};
+pub use parallel::*;
+pub use radix::*;
+pub use records::{FileSortConfig, InputFormat, KeySpec};
+pub use report::{ReportFormat, ReportRow};
+pub use select::*;
+pub use sorts::*;
+pub use stats::SortStats;
+pub use trace::{Replay, SortEvent};
+
+// Déclaration du module de tests
+#[cfg(test)]
+#[path = "tests/mod.rs"]
+mod tests;
@@ -1,110 +1,155 @@
-use rand::Rng;
-use std::io;
+mod cli;
+mod visualizer;
+
+use bubble_short::{bench, complexity, external, records, report, Algorithm, Distribution, ReportFormat};
+use cli::{BenchCommand, Command, CompareCommand, SortCommand};
+use std::fs::File;
+use std::io::{self, BufReader, BufWriter};
+use std::process;
 use std::time::Instant;
 
-fn bubble_sort(arr: &mut [i32]) {
-    let n = arr.len();
-    for i in 0..n {
-        for j in 0..(n - i - 1) {
-            if arr[j] > arr[j + 1] {
-                arr.swap(j, j + 1);
+fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+
+    match cli::parse_args(&args) {
+        Ok(Command::Menu) => menu(),
+        Ok(Command::Help) => println!("{}", cli::USAGE),
+        Ok(Command::Bench(command)) => {
+            if let Err(message) = run_bench(&command) {
+                eprintln!("Erreur : {}", message);
+                process::exit(1);
             }
         }
-    }
-}
-
-fn selection_sort(arr: &mut [i32]) {
-    let n = arr.len();
-    for i in 0..n {
-        let mut min_index = i;
-        for j in (i + 1)..n {
-            if arr[j] < arr[min_index] {
-                min_index = j;
+        Ok(Command::Compare(command)) => match compare_reports(&command) {
+            Ok(true) => process::exit(1),
+            Ok(false) => {}
+            Err(message) => {
+                eprintln!("Erreur : {}", message);
+                process::exit(2);
             }
+        },
+        Ok(Command::Complexity(config)) => {
+            println!(
+                "Complexité ({}) : {}, tailles {}..{}, {} exécution(s), graine {}",
+                config.metric,
+                config.distribution,
+                config.sizes.first().unwrap_or(&0),
+                config.sizes.last().unwrap_or(&0),
+                config.runs,
+                config.seed
+            );
+            let results = complexity::sweep(&config);
+            print!("{}", complexity::format_fits(&results));
+            println!();
+            print!("{}", complexity::plot(&results, 64, 16));
         }
-        arr.swap(i, min_index);
-    }
-}
-
-fn insertion_sort(arr: &mut [i32]) {
-    let n = arr.len();
-    for i in 1..n {
-        let key = arr[i];
-        let mut j = i as i32 - 1;
-        while j >= 0 && arr[j as usize] > key {
-            arr[(j + 1) as usize] = arr[j as usize];
-            j -= 1;
+        Ok(Command::Visualize(config)) => {
+            if let Err(error) = visualizer::run(&config) {
+                eprintln!("Erreur du terminal : {}", error);
+                process::exit(1);
+            }
+        }
+        Ok(Command::Sort(command)) => {
+            if let Err(message) = sort_file(&command) {
+                eprintln!("Erreur : {}", message);
+                process::exit(1);
+            }
+        }
+        Err(message) => {
+            eprintln!("Erreur : {}", message);
+            eprintln!("{}", cli::USAGE);
+            process::exit(2);
         }
-        arr[(j + 1) as usize] = key;
     }
 }
 
-fn merge_sort(arr: &mut [i32]) {
-    let n = arr.len();
-    if n > 1 {
-        let mid = n / 2;
-        merge_sort(&mut arr[0..mid]);   // Sort left half
-        merge_sort(&mut arr[mid..]);     // Sort right half
-        merge(arr);                      // Merge them back together
+/// Lance le benchmark, affiche le tableau (ou le rapport demandé) et écrit
+/// les rapports.
+fn run_bench(command: &BenchCommand) -> Result<(), String> {
+    let config = &command.config;
+    if let Some(format) = command.format {
+        print!("{}", report::export(&bench::run(config), format));
+        return Ok(());
     }
-}
 
-fn merge(arr: &mut [i32]) {
-    let mid = arr.len() / 2;
-    let left = arr[0..mid].to_vec();   // Create a copy of the left half
-    let right = arr[mid..].to_vec();    // Create a copy of the right half
-
-    let (mut i, mut j, mut k) = (0, 0, 0);
-
-    while i < left.len() && j < right.len() {
-        if left[i] <= right[j] {
-            arr[k] = left[i];
-            i += 1;
-        } else {
-            arr[k] = right[j];
-            j += 1;
-        }
-        k += 1;
+    println!(
+        "Benchmark : {} élément(s), {} exécution(s), graine {}",
+        config.size, config.runs, config.seed
+    );
+    let results = bench::run(config);
+    print!("{}", bench::format_table(&results));
+    print!("{}", bench::format_speedups(&results));
+
+    for (path, format) in &command.reports {
+        std::fs::write(path, report::export(&results, *format))
+            .map_err(|e| format!("Impossible d'écrire {} : {}", path, e))?;
+        println!("Rapport {} écrit dans {}", format, path);
     }
+    Ok(())
+}
 
-    while i < left.len() {
-        arr[k] = left[i];
-        i += 1;
-        k += 1;
+/// Compare deux rapports ; renvoie `true` si une régression est détectée.
+fn compare_reports(command: &CompareCommand) -> Result<bool, String> {
+    let load = |path: &str| {
+        let format = ReportFormat::from_path(path.as_ref()).unwrap_or(ReportFormat::Json);
+        let text = std::fs::read_to_string(path).map_err(|e| format!("Impossible de lire {} : {}", path, e))?;
+        report::load(&text, format).map_err(|e| format!("{} : {}", path, e))
+    };
+    let baseline = load(&command.baseline)?;
+    let current = load(&command.current)?;
+
+    let comparisons = report::compare(&baseline, &current, command.threshold);
+    if comparisons.is_empty() {
+        return Err("Aucune mesure commune aux deux rapports".to_string());
     }
+    print!("{}", report::format_comparison(&comparisons));
 
-    while j < right.len() {
-        arr[k] = right[j];
-        j += 1;
-        k += 1;
+    let regressions = comparisons.iter().filter(|c| c.regression).count();
+    let unmatched = current.len() - comparisons.len();
+    if unmatched > 0 {
+        println!("{} mesure(s) sans équivalent dans {}", unmatched, command.baseline);
     }
-}
-
-
-fn quick_sort(arr: &mut [i32]) {
-    if arr.len() > 1 {
-        let pivot_index = partition(arr);
-        let (left, right) = arr.split_at_mut(pivot_index);
-        quick_sort(left);
-        quick_sort(&mut right[1..]);
+    if regressions > 0 {
+        println!("{} régression(s) au-delà de {} %", regressions, command.threshold);
+    } else {
+        println!("Aucune régression au-delà de {} %", command.threshold);
     }
+    Ok(regressions > 0)
 }
 
-fn partition(arr: &mut [i32]) -> usize {
-    let pivot_index = arr.len() / 2;
-    arr.swap(pivot_index, arr.len() - 1);
-    let mut store_index = 0;
-    for i in 0..arr.len() - 1 {
-        if arr[i] < arr[arr.len() - 1] {
-            arr.swap(i, store_index);
-            store_index += 1;
+/// Trie un fichier (ou l'entrée standard) vers un fichier (ou la sortie standard).
+fn sort_file(command: &SortCommand) -> Result<(), String> {
+    let reader: Box<dyn io::BufRead> = match &command.input {
+        Some(path) => Box::new(BufReader::new(
+            File::open(path).map_err(|e| format!("Impossible d'ouvrir {} : {}", path, e))?,
+        )),
+        None => Box::new(io::stdin().lock()),
+    };
+    let writer: Box<dyn io::Write> = match &command.output {
+        Some(path) => Box::new(BufWriter::new(
+            File::create(path).map_err(|e| format!("Impossible de créer {} : {}", path, e))?,
+        )),
+        None => Box::new(BufWriter::new(io::stdout().lock())),
+    };
+
+    let count = match &command.external {
+        Some(settings) => {
+            let stats = external::external_sort(reader, writer, &command.config, settings)?;
+            eprintln!(
+                "Tri externe : {} suite(s) sur disque, {} passe(s) de fusion intermédiaire(s)",
+                stats.runs, stats.merge_passes
+            );
+            stats.records
         }
+        None => records::sort_stream(reader, writer, &command.config)?,
+    };
+    if let Some(path) = &command.output {
+        println!("{} ligne(s) triée(s) dans {}", count, path);
     }
-    arr.swap(store_index, arr.len() - 1);
-    store_index
+    Ok(())
 }
 
-fn main() {
+fn menu() {
     println!("Menu de Tri");
 
     // Demander le nombre d'éléments à trier
@@ -113,39 +158,54 @@ fn main() {
     io::stdin().read_line(&mut size_input).expect("Erreur de lecture");
     let size: usize = size_input.trim().parse().expect("Veuillez entrer un nombre valide");
 
-    // Générer un tableau d'éléments aléatoires
-    let mut arr: Vec<i32> = (0..size).map(|_| rand::thread_rng().gen_range(0..1000)).collect();
-    
+    // Choisir la forme des données à trier
+    println!("Choisissez une distribution (Entrée pour random) :");
+    for (i, distribution) in Distribution::ALL.iter().enumerate() {
+        println!("{}. {}", i + 1, distribution);
+    }
+    let mut dist_input = String::new();
+    io::stdin().read_line(&mut dist_input).expect("Erreur de lecture");
+    let distribution = match dist_input.trim() {
+        "" => Distribution::Random,
+        choice => {
+            let index: usize = choice.parse().expect("Veuillez entrer un nombre valide");
+            match index.checked_sub(1).and_then(|i| Distribution::ALL.get(i)) {
+                Some(&distribution) => distribution,
+                None => {
+                    println!("Choix invalide");
+                    return;
+                }
+            }
+        }
+    };
+
+    // Générer le tableau d'éléments
+    let mut arr = distribution.generate(size, rand::random());
 
     // Afficher le menu de choix des algorithmes de tri
     println!("Choisissez un algorithme de tri :");
-    println!("1. Bubble Sort");
-    println!("2. Selection Sort");
-    println!("3. Insertion Sort");
-    println!("4. Merge Sort");
-    println!("5. Quick Sort");
+    for (i, algorithm) in Algorithm::ALL.iter().enumerate() {
+        println!("{}. {}", i + 1, algorithm.label());
+    }
     println!("Veuillez entrer le numéro de l'algorithme :");
 
     let mut choice_input = String::new();
     io::stdin().read_line(&mut choice_input).expect("Erreur de lecture");
-    let choice: u32 = choice_input.trim().parse().expect("Veuillez entrer un nombre valide");
+    let choice: usize = choice_input.trim().parse().expect("Veuillez entrer un nombre valide");
 
     // Effectuer le tri en fonction du choix de l'utilisateur
+    let Some(&algorithm) = choice.checked_sub(1).and_then(|i| Algorithm::ALL.get(i)) else {
+        println!("Choix invalide");
+        return;
+    };
+
+    // Mesure du coût algorithmique sur une copie, avant le tri chronométré
+    let stats = algorithm.sort_instrumented(&mut arr.clone());
+
     let start = Instant::now();
-    match choice {
-        1 => bubble_sort(&mut arr),
-        2 => selection_sort(&mut arr),
-        3 => insertion_sort(&mut arr),
-        4 => {
-            let mut temp_arr = arr.clone();
-            merge_sort(&mut temp_arr);
-            arr.copy_from_slice(&temp_arr);
-        }
-        5 => quick_sort(&mut arr),
-        _ => println!("Choix invalide"),
-    }
+    algorithm.sort(&mut arr);
     let duration = start.elapsed();
 
-
     println!("Temps de tri : {:?}", duration);
+    println!("Coût : {}", stats);
 }
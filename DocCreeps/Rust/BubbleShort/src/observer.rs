@@ -0,0 +1,48 @@
+/// Reçoit les opérations élémentaires effectuées par un algorithme de tri.
+///
+/// Les indices sont absolus dans le tableau trié, même lorsque l'algorithme
+/// travaille sur une sous-tranche (tri fusion, tri rapide).
+pub trait SortObserver<T> {
+    /// Comparaison entre les éléments aux positions `i` et `j`.
+    fn compare(&mut self, _i: usize, _j: usize) {}
+
+    /// Échange des éléments aux positions `i` et `j`.
+    fn swap(&mut self, _i: usize, _j: usize) {}
+
+    /// Écriture de `value` à la position `i` (copie depuis un tampon).
+    fn write(&mut self, _i: usize, _value: &T) {}
+
+    /// Entrée dans un appel récursif de profondeur `depth` (0 = appel initial).
+    fn recurse(&mut self, _depth: usize) {}
+
+    /// Allocation d'un tampon auxiliaire de `len` éléments.
+    fn allocate(&mut self, _len: usize) {}
+}
+
+/// Observateur qui ignore tout : utilisé par les fonctions de tri publiques.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct NoopObserver;
+
+impl<T> SortObserver<T> for NoopObserver {}
+
+impl<T, O: SortObserver<T> + ?Sized> SortObserver<T> for &mut O {
+    fn compare(&mut self, i: usize, j: usize) {
+        (**self).compare(i, j);
+    }
+
+    fn swap(&mut self, i: usize, j: usize) {
+        (**self).swap(i, j);
+    }
+
+    fn write(&mut self, i: usize, value: &T) {
+        (**self).write(i, value);
+    }
+
+    fn recurse(&mut self, depth: usize) {
+        (**self).recurse(depth);
+    }
+
+    fn allocate(&mut self, len: usize) {
+        (**self).allocate(len);
+    }
+}
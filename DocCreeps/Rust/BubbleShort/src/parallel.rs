@@ -0,0 +1,150 @@
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, OnceLock};
+
+use rayon::{ThreadPool, ThreadPoolBuilder};
+
+use crate::observer::NoopObserver;
+use crate::sorts::{
+    choose_pivot, depth_budget, merge, merge_sort_observed, partition_three_way, quick_sort_observed, PivotStrategy,
+};
+
+/// Réglages des tris parallèles.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Parallelism {
+    /// Nombre de threads du pool (0 = un par cœur).
+    pub threads: usize,
+    /// En dessous de cette taille, la sous-tranche est triée séquentiellement.
+    pub cutoff: usize,
+}
+
+impl Parallelism {
+    pub const DEFAULT: Parallelism = Parallelism { threads: 0, cutoff: 4096 };
+}
+
+impl Default for Parallelism {
+    fn default() -> Self {
+        Parallelism::DEFAULT
+    }
+}
+
+/// Renvoie le pool de `threads` threads, créé au premier appel puis réutilisé
+/// pour que sa création ne fausse pas les mesures.
+fn pool(threads: usize) -> Arc<ThreadPool> {
+    static POOLS: OnceLock<Mutex<HashMap<usize, Arc<ThreadPool>>>> = OnceLock::new();
+
+    let mut pools = POOLS.get_or_init(Default::default).lock().unwrap();
+    pools
+        .entry(threads)
+        .or_insert_with(|| {
+            Arc::new(
+                ThreadPoolBuilder::new()
+                    .num_threads(threads)
+                    .build()
+                    .expect("Impossible de créer le pool de threads"),
+            )
+        })
+        .clone()
+}
+
+// ====================================================================
+// PARALLEL MERGE SORT
+// ====================================================================
+
+/// Tri fusion parallèle : les deux moitiés sont triées en parallèle
+/// (fork-join), puis fusionnées séquentiellement.
+pub fn par_merge_sort<T: Ord + Clone + Send>(arr: &mut [T], parallelism: Parallelism) {
+    par_merge_sort_by(arr, parallelism, T::cmp);
+}
+
+/// Tri fusion parallèle avec une fonction de comparaison personnalisée.
+pub fn par_merge_sort_by<T, F>(arr: &mut [T], parallelism: Parallelism, compare: F)
+where
+    T: Clone + Send,
+    F: Fn(&T, &T) -> Ordering + Sync,
+{
+    let cutoff = parallelism.cutoff.max(2);
+    pool(parallelism.threads).install(|| par_merge_sort_rec(arr, cutoff, &compare));
+}
+
+/// Tri fusion parallèle selon une clé extraite de chaque élément.
+pub fn par_merge_sort_by_key<T, K, F>(arr: &mut [T], parallelism: Parallelism, key: F)
+where
+    T: Clone + Send,
+    K: Ord,
+    F: Fn(&T) -> K + Sync,
+{
+    par_merge_sort_by(arr, parallelism, |a, b| key(a).cmp(&key(b)));
+}
+
+fn par_merge_sort_rec<T, F>(arr: &mut [T], cutoff: usize, compare: &F)
+where
+    T: Clone + Send,
+    F: Fn(&T, &T) -> Ordering + Sync,
+{
+    let n = arr.len();
+    if n <= cutoff {
+        merge_sort_observed(arr, &mut |a: &T, b: &T| compare(a, b), &mut NoopObserver);
+        return;
+    }
+
+    let mid = n / 2;
+    let (left, right) = arr.split_at_mut(mid);
+    rayon::join(
+        || par_merge_sort_rec(left, cutoff, compare),
+        || par_merge_sort_rec(right, cutoff, compare),
+    );
+    merge(arr, mid, &mut |a: &T, b: &T| compare(a, b), &mut NoopObserver, 0);
+}
+
+// ====================================================================
+// PARALLEL QUICK SORT
+// ====================================================================
+
+/// Tri rapide parallèle : après chaque partition (pivot « ninther », partition
+/// en trois), les côtés inférieur et supérieur sont triés en parallèle.
+pub fn par_quick_sort<T: Ord + Send>(arr: &mut [T], parallelism: Parallelism) {
+    par_quick_sort_by(arr, parallelism, T::cmp);
+}
+
+/// Tri rapide parallèle avec une fonction de comparaison personnalisée.
+pub fn par_quick_sort_by<T, F>(arr: &mut [T], parallelism: Parallelism, compare: F)
+where
+    T: Send,
+    F: Fn(&T, &T) -> Ordering + Sync,
+{
+    let cutoff = parallelism.cutoff.max(2);
+    let budget = depth_budget(arr.len());
+    pool(parallelism.threads).install(|| par_quick_sort_rec(arr, cutoff, budget, &compare));
+}
+
+/// Tri rapide parallèle selon une clé extraite de chaque élément.
+pub fn par_quick_sort_by_key<T, K, F>(arr: &mut [T], parallelism: Parallelism, key: F)
+where
+    T: Send,
+    K: Ord,
+    F: Fn(&T) -> K + Sync,
+{
+    par_quick_sort_by(arr, parallelism, |a, b| key(a).cmp(&key(b)));
+}
+
+fn par_quick_sort_rec<T, F>(arr: &mut [T], cutoff: usize, budget: usize, compare: &F)
+where
+    T: Send,
+    F: Fn(&T, &T) -> Ordering + Sync,
+{
+    // Budget épuisé : la version séquentielle se replie sur le tri par tas.
+    if arr.len() <= cutoff || budget == 0 {
+        quick_sort_observed(arr, PivotStrategy::default(), &mut |a: &T, b: &T| compare(a, b), &mut NoopObserver);
+        return;
+    }
+
+    let mut compare_mut = |a: &T, b: &T| compare(a, b);
+    let pivot_index = choose_pivot(arr, PivotStrategy::default(), &mut compare_mut, &mut NoopObserver, 0, &mut 0);
+    let (lt, gt) = partition_three_way(arr, pivot_index, &mut compare_mut, &mut NoopObserver, 0);
+    let (left, rest) = arr.split_at_mut(lt);
+    rayon::join(
+        || par_quick_sort_rec(left, cutoff, budget - 1, compare),
+        || par_quick_sort_rec(&mut rest[gt - lt..], cutoff, budget - 1, compare),
+    );
+}
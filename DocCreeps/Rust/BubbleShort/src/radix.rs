@@ -0,0 +1,183 @@
+use crate::observer::{NoopObserver, SortObserver};
+
+/// Clé entière utilisable par les tris non comparatifs (comptage, radix).
+///
+/// `radix_key` doit préserver l'ordre : `a < b` ⇔ `a.radix_key() < b.radix_key()`.
+pub trait RadixKey: Copy {
+    fn radix_key(self) -> u64;
+}
+
+macro_rules! impl_radix_key_unsigned {
+    ($($t:ty),*) => {
+        $(impl RadixKey for $t {
+            fn radix_key(self) -> u64 {
+                self as u64
+            }
+        })*
+    };
+}
+
+macro_rules! impl_radix_key_signed {
+    ($($t:ty),*) => {
+        $(impl RadixKey for $t {
+            fn radix_key(self) -> u64 {
+                // Inverser le bit de signe place les négatifs avant les positifs.
+                (self as i64 as u64) ^ (1 << 63)
+            }
+        })*
+    };
+}
+
+impl_radix_key_unsigned!(u8, u16, u32, u64, usize, bool, char);
+impl_radix_key_signed!(i8, i16, i32, i64, isize);
+
+/// Au-delà de cet écart entre clé minimale et maximale, le tri par comptage
+/// bascule sur le tri radix pour ne pas allouer un tableau de compteurs géant.
+pub const COUNTING_SORT_MAX_RANGE: u64 = 1 << 20;
+
+// ====================================================================
+// COUNTING SORT
+// ====================================================================
+
+/// Tri par comptage. O(n + k) où k est l'écart entre valeurs extrêmes, stable.
+///
+/// Non comparatif : il n'existe pas de variante `_by`.
+pub fn counting_sort<T: RadixKey>(arr: &mut [T]) {
+    counting_sort_by_key(arr, |&x| x);
+}
+
+/// Tri par comptage selon une clé entière extraite de chaque élément.
+pub fn counting_sort_by_key<T, K, F>(arr: &mut [T], key: F)
+where
+    T: Clone,
+    K: RadixKey,
+    F: FnMut(&T) -> K,
+{
+    counting_sort_observed(arr, key, &mut NoopObserver);
+}
+
+pub(crate) fn counting_sort_observed<T, K, F, O>(arr: &mut [T], mut key: F, observer: &mut O)
+where
+    T: Clone,
+    K: RadixKey,
+    F: FnMut(&T) -> K,
+    O: SortObserver<T>,
+{
+    if arr.len() < 2 {
+        return;
+    }
+
+    let keys: Vec<u64> = arr.iter().map(|x| key(x).radix_key()).collect();
+    let min = *keys.iter().min().unwrap();
+    let max = *keys.iter().max().unwrap();
+    if max - min > COUNTING_SORT_MAX_RANGE {
+        radix_sort_keys(arr, &keys, observer);
+        return;
+    }
+
+    let range = (max - min) as usize + 1;
+    observer.allocate(range);
+    let mut counts = vec![0usize; range + 1];
+    for &k in &keys {
+        counts[(k - min) as usize + 1] += 1;
+    }
+    for i in 1..counts.len() {
+        counts[i] += counts[i - 1];
+    }
+
+    // counts[v] est maintenant la première position de la valeur v
+    observer.allocate(arr.len());
+    let mut output: Vec<Option<T>> = vec![None; arr.len()];
+    for (item, &k) in arr.iter().zip(&keys) {
+        let slot = &mut counts[(k - min) as usize];
+        output[*slot] = Some(item.clone());
+        *slot += 1;
+    }
+    write_back(arr, output, observer);
+}
+
+// ====================================================================
+// RADIX SORT (LSD)
+// ====================================================================
+
+/// Tri radix LSD, octet par octet. O(n · w) où w est la taille de la clé, stable.
+///
+/// Non comparatif : il n'existe pas de variante `_by`.
+pub fn radix_sort<T: RadixKey>(arr: &mut [T]) {
+    radix_sort_by_key(arr, |&x| x);
+}
+
+/// Tri radix selon une clé entière extraite de chaque élément.
+pub fn radix_sort_by_key<T, K, F>(arr: &mut [T], key: F)
+where
+    T: Clone,
+    K: RadixKey,
+    F: FnMut(&T) -> K,
+{
+    radix_sort_observed(arr, key, &mut NoopObserver);
+}
+
+pub(crate) fn radix_sort_observed<T, K, F, O>(arr: &mut [T], mut key: F, observer: &mut O)
+where
+    T: Clone,
+    K: RadixKey,
+    F: FnMut(&T) -> K,
+    O: SortObserver<T>,
+{
+    if arr.len() < 2 {
+        return;
+    }
+    let keys: Vec<u64> = arr.iter().map(|x| key(x).radix_key()).collect();
+    radix_sort_keys(arr, &keys, observer);
+}
+
+/// Tri radix de `arr` selon les clés précalculées `keys` (même longueur).
+fn radix_sort_keys<T, O>(arr: &mut [T], keys: &[u64], observer: &mut O)
+where
+    T: Clone,
+    O: SortObserver<T>,
+{
+    // On trie une permutation d'indices, puis on applique l'ordre final en une passe.
+    observer.allocate(arr.len());
+    let mut order: Vec<usize> = (0..arr.len()).collect();
+    let mut buffer = vec![0usize; arr.len()];
+
+    for shift in (0..64).step_by(8) {
+        let digit = |i: usize| ((keys[i] >> shift) & 0xFF) as usize;
+
+        // Passe inutile si tous les éléments ont le même octet
+        let first = digit(order[0]);
+        if order.iter().all(|&i| digit(i) == first) {
+            continue;
+        }
+
+        let mut counts = [0usize; 257];
+        for &i in &order {
+            counts[digit(i) + 1] += 1;
+        }
+        for d in 1..counts.len() {
+            counts[d] += counts[d - 1];
+        }
+        for &i in &order {
+            let slot = &mut counts[digit(i)];
+            buffer[*slot] = i;
+            *slot += 1;
+        }
+        std::mem::swap(&mut order, &mut buffer);
+    }
+
+    observer.allocate(arr.len());
+    let output: Vec<Option<T>> = order.iter().map(|&i| Some(arr[i].clone())).collect();
+    write_back(arr, output, observer);
+}
+
+/// Recopie le tampon trié dans `arr` en signalant chaque écriture.
+fn write_back<T, O>(arr: &mut [T], output: Vec<Option<T>>, observer: &mut O)
+where
+    O: SortObserver<T>,
+{
+    for (i, item) in output.into_iter().enumerate() {
+        arr[i] = item.expect("chaque position est remplie exactement une fois");
+        observer.write(i, &arr[i]);
+    }
+}
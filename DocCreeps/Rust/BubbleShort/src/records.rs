@@ -0,0 +1,241 @@
+use std::cmp::Ordering;
+use std::io::{self, BufRead, Write};
+use std::str::FromStr;
+
+use crate::algorithm::Algorithm;
+
+/// Clé de tri sur une colonne, à la manière de `sort -k` : `2`, `2n`, `3nr`...
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KeySpec {
+    /// Numéro de colonne, à partir de 1.
+    pub column: usize,
+    /// Comparaison numérique plutôt que lexicographique.
+    pub numeric: bool,
+    /// Ordre décroissant.
+    pub reverse: bool,
+}
+
+impl FromStr for KeySpec {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let digits_end = s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len());
+        let column: usize = s[..digits_end]
+            .parse()
+            .map_err(|_| format!("Clé invalide : '{}' (exemple : 2nr)", s))?;
+        if column == 0 {
+            return Err("Les colonnes sont numérotées à partir de 1".to_string());
+        }
+
+        let mut key = KeySpec { column, numeric: false, reverse: false };
+        for flag in s[digits_end..].chars() {
+            match flag {
+                'n' => key.numeric = true,
+                'r' => key.reverse = true,
+                other => return Err(format!("Option de clé inconnue : '{}' dans '{}'", other, s)),
+            }
+        }
+        Ok(key)
+    }
+}
+
+/// Format des lignes en entrée.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InputFormat {
+    /// Une ligne par enregistrement ; colonnes séparées par `delimiter`,
+    /// ou par des blancs si `None`.
+    Lines { delimiter: Option<char> },
+    /// CSV : champs éventuellement entre guillemets, pouvant contenir le
+    /// séparateur ou des retours à la ligne.
+    Csv { delimiter: char },
+}
+
+/// Paramètres d'un tri de fichier.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FileSortConfig {
+    pub algorithm: Algorithm,
+    pub format: InputFormat,
+    /// Clés de tri, par ordre de priorité. Vide : toute la ligne.
+    pub keys: Vec<KeySpec>,
+    /// Comparaison numérique de la ligne entière quand aucune clé n'est donnée.
+    pub numeric: bool,
+    /// Inverse l'ordre final.
+    pub reverse: bool,
+    /// La première ligne est un en-tête, laissé en tête de la sortie.
+    pub header: bool,
+}
+
+/// Un enregistrement : le texte d'origine, réécrit tel quel, et ses colonnes.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Record {
+    pub text: String,
+    pub fields: Vec<String>,
+}
+
+impl Record {
+    /// Place occupée en mémoire, approximativement.
+    pub fn memory_size(&self) -> usize {
+        std::mem::size_of::<Record>()
+            + self.text.capacity()
+            + self.fields.iter().map(|f| std::mem::size_of::<String>() + f.capacity()).sum::<usize>()
+    }
+
+    /// Valeur de la colonne `column` (à partir de 1), vide si absente.
+    pub fn field(&self, column: usize) -> &str {
+        self.fields.get(column - 1).map_or("", String::as_str)
+    }
+}
+
+/// Lit les enregistrements un par un, sans charger tout le flux en mémoire.
+pub struct RecordReader<R> {
+    lines: io::Lines<R>,
+    format: InputFormat,
+}
+
+impl<R: BufRead> RecordReader<R> {
+    pub fn new(reader: R, format: InputFormat) -> Self {
+        Self { lines: reader.lines(), format }
+    }
+}
+
+impl<R: BufRead> Iterator for RecordReader<R> {
+    type Item = io::Result<Record>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let line = match self.lines.next()? {
+            Ok(line) => line,
+            Err(e) => return Some(Err(e)),
+        };
+
+        match self.format {
+            InputFormat::Lines { delimiter } => {
+                let fields = match delimiter {
+                    Some(d) => line.split(d).map(str::to_string).collect(),
+                    None => line.split_whitespace().map(str::to_string).collect(),
+                };
+                Some(Ok(Record { text: line, fields }))
+            }
+            InputFormat::Csv { delimiter } => {
+                let mut text = line;
+                // Un nombre impair de guillemets : le champ continue sur la ligne suivante.
+                while text.matches('"').count() % 2 == 1 {
+                    match self.lines.next() {
+                        Some(Ok(next)) => {
+                            text.push('\n');
+                            text.push_str(&next);
+                        }
+                        Some(Err(e)) => return Some(Err(e)),
+                        None => {
+                            return Some(Err(io::Error::new(
+                                io::ErrorKind::InvalidData,
+                                "Guillemet non fermé en fin de fichier CSV",
+                            )))
+                        }
+                    }
+                }
+                let fields = parse_csv_record(&text, delimiter);
+                Some(Ok(Record { text, fields }))
+            }
+        }
+    }
+}
+
+/// Lit tous les enregistrements de `reader`.
+pub fn read_records(reader: impl BufRead, format: InputFormat) -> io::Result<Vec<Record>> {
+    RecordReader::new(reader, format).collect()
+}
+
+/// Découpe un enregistrement CSV complet en champs (guillemets retirés, `""` → `"`).
+pub fn parse_csv_record(text: &str, delimiter: char) -> Vec<String> {
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut chars = text.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if in_quotes && chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            '"' => in_quotes = !in_quotes,
+            c if c == delimiter && !in_quotes => fields.push(std::mem::take(&mut field)),
+            c => field.push(c),
+        }
+    }
+    fields.push(field);
+    fields
+}
+
+/// Compare deux valeurs en numérique : les valeurs non numériques passent
+/// avant les nombres et sont comparées entre elles comme du texte.
+fn compare_numeric(a: &str, b: &str) -> Ordering {
+    match (a.trim().parse::<f64>(), b.trim().parse::<f64>()) {
+        (Ok(x), Ok(y)) => x.total_cmp(&y),
+        (Ok(_), Err(_)) => Ordering::Greater,
+        (Err(_), Ok(_)) => Ordering::Less,
+        (Err(_), Err(_)) => a.cmp(b),
+    }
+}
+
+/// Compare deux enregistrements selon la configuration. Les égalités sont
+/// laissées telles quelles : un algorithme stable conserve l'ordre d'entrée.
+pub fn compare_records(a: &Record, b: &Record, config: &FileSortConfig) -> Ordering {
+    let ordering = if config.keys.is_empty() {
+        if config.numeric {
+            compare_numeric(&a.text, &b.text)
+        } else {
+            a.text.cmp(&b.text)
+        }
+    } else {
+        config
+            .keys
+            .iter()
+            .map(|key| {
+                let (x, y) = (a.field(key.column), b.field(key.column));
+                let ordering = if key.numeric { compare_numeric(x, y) } else { x.cmp(y) };
+                if key.reverse {
+                    ordering.reverse()
+                } else {
+                    ordering
+                }
+            })
+            .find(|&o| o != Ordering::Equal)
+            .unwrap_or(Ordering::Equal)
+    };
+
+    if config.reverse {
+        ordering.reverse()
+    } else {
+        ordering
+    }
+}
+
+/// Lit `reader`, trie les enregistrements et les écrit dans `writer`.
+/// Renvoie le nombre d'enregistrements triés (en-tête exclu).
+pub fn sort_stream(reader: impl BufRead, mut writer: impl Write, config: &FileSortConfig) -> Result<usize, String> {
+    if !config.algorithm.is_comparison() {
+        return Err(format!(
+            "{} ne sait trier que des entiers : choisissez un tri comparatif",
+            config.algorithm.label()
+        ));
+    }
+
+    let mut records = read_records(reader, config.format).map_err(|e| format!("Erreur de lecture : {}", e))?;
+    let header = if config.header && !records.is_empty() {
+        Some(records.remove(0))
+    } else {
+        None
+    };
+
+    config
+        .algorithm
+        .sort_by(&mut records, |a, b| compare_records(a, b, config))?;
+
+    let write_error = |e: io::Error| format!("Erreur d'écriture : {}", e);
+    for record in header.iter().chain(&records) {
+        writeln!(writer, "{}", record.text).map_err(write_error)?;
+    }
+    writer.flush().map_err(write_error)?;
+    Ok(records.len())
+}
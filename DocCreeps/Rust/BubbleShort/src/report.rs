@@ -0,0 +1,319 @@
+use std::fmt;
+use std::path::Path;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+use crate::bench::BenchResult;
+use crate::records::parse_csv_record;
+
+/// Format d'un rapport de benchmark exporté.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReportFormat {
+    Csv,
+    Json,
+    /// Tableau Markdown : lisible, mais ne peut pas être relu par `compare`.
+    Markdown,
+}
+
+impl ReportFormat {
+    pub fn name(self) -> &'static str {
+        match self {
+            ReportFormat::Csv => "csv",
+            ReportFormat::Json => "json",
+            ReportFormat::Markdown => "md",
+        }
+    }
+
+    /// Déduit le format de l'extension du fichier.
+    pub fn from_path(path: &Path) -> Option<ReportFormat> {
+        path.extension()?.to_str()?.parse().ok()
+    }
+}
+
+impl fmt::Display for ReportFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for ReportFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "csv" => Ok(ReportFormat::Csv),
+            "json" => Ok(ReportFormat::Json),
+            "md" | "markdown" => Ok(ReportFormat::Markdown),
+            _ => Err(format!("Format de rapport inconnu : '{}' (csv, json ou md)", s)),
+        }
+    }
+}
+
+/// Une ligne de rapport : un algorithme sur une distribution, temps en
+/// nanosecondes. Les noms sont ceux acceptés par `--algo` et `--dist`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReportRow {
+    pub algorithm: String,
+    pub distribution: String,
+    pub size: usize,
+    pub seed: u64,
+    pub runs: usize,
+    pub min_ns: u64,
+    pub median_ns: u64,
+    pub max_ns: u64,
+    pub comparisons: u64,
+    pub swaps: u64,
+    pub writes: u64,
+    pub max_depth: usize,
+    pub allocations: u64,
+    pub allocated_elements: u64,
+}
+
+impl From<&BenchResult> for ReportRow {
+    fn from(result: &BenchResult) -> Self {
+        let nanos = |d: std::time::Duration| d.as_nanos().min(u64::MAX as u128) as u64;
+        ReportRow {
+            algorithm: result.algorithm.to_string(),
+            distribution: result.distribution.to_string(),
+            size: result.size,
+            seed: result.seed,
+            runs: result.runs,
+            min_ns: nanos(result.min),
+            median_ns: nanos(result.median),
+            max_ns: nanos(result.max),
+            comparisons: result.stats.comparisons,
+            swaps: result.stats.swaps,
+            writes: result.stats.writes,
+            max_depth: result.stats.max_depth,
+            allocations: result.stats.allocations,
+            allocated_elements: result.stats.allocated_elements,
+        }
+    }
+}
+
+const CSV_COLUMNS: [&str; 14] = [
+    "algorithm",
+    "distribution",
+    "size",
+    "seed",
+    "runs",
+    "min_ns",
+    "median_ns",
+    "max_ns",
+    "comparisons",
+    "swaps",
+    "writes",
+    "max_depth",
+    "allocations",
+    "allocated_elements",
+];
+
+impl ReportRow {
+    fn csv_values(&self) -> [String; 14] {
+        [
+            self.algorithm.clone(),
+            self.distribution.clone(),
+            self.size.to_string(),
+            self.seed.to_string(),
+            self.runs.to_string(),
+            self.min_ns.to_string(),
+            self.median_ns.to_string(),
+            self.max_ns.to_string(),
+            self.comparisons.to_string(),
+            self.swaps.to_string(),
+            self.writes.to_string(),
+            self.max_depth.to_string(),
+            self.allocations.to_string(),
+            self.allocated_elements.to_string(),
+        ]
+    }
+
+    /// Reconstruit une ligne à partir de valeurs CSV rangées dans l'ordre de `CSV_COLUMNS`.
+    fn from_csv_values(values: &[&str]) -> Result<Self, String> {
+        fn number<N: FromStr>(values: &[&str], index: usize) -> Result<N, String> {
+            values[index]
+                .trim()
+                .parse()
+                .map_err(|_| format!("Valeur invalide pour {} : '{}'", CSV_COLUMNS[index], values[index]))
+        }
+        Ok(ReportRow {
+            algorithm: values[0].to_string(),
+            distribution: values[1].to_string(),
+            size: number(values, 2)?,
+            seed: number(values, 3)?,
+            runs: number(values, 4)?,
+            min_ns: number(values, 5)?,
+            median_ns: number(values, 6)?,
+            max_ns: number(values, 7)?,
+            comparisons: number(values, 8)?,
+            swaps: number(values, 9)?,
+            writes: number(values, 10)?,
+            max_depth: number(values, 11)?,
+            allocations: number(values, 12)?,
+            allocated_elements: number(values, 13)?,
+        })
+    }
+
+    /// Clé d'appariement entre deux rapports.
+    fn key(&self) -> (&str, &str, usize) {
+        (&self.algorithm, &self.distribution, self.size)
+    }
+}
+
+// ====================================================================
+// EXPORT
+// ====================================================================
+
+/// Met en forme les résultats d'un benchmark dans le format demandé.
+pub fn export(results: &[BenchResult], format: ReportFormat) -> String {
+    let rows: Vec<ReportRow> = results.iter().map(ReportRow::from).collect();
+    match format {
+        ReportFormat::Csv => {
+            let mut out = CSV_COLUMNS.join(",");
+            out.push('\n');
+            for row in &rows {
+                out.push_str(&row.csv_values().join(","));
+                out.push('\n');
+            }
+            out
+        }
+        ReportFormat::Json => {
+            let mut out = serde_json::to_string_pretty(&rows).expect("un rapport est toujours sérialisable");
+            out.push('\n');
+            out
+        }
+        ReportFormat::Markdown => {
+            let mut out = String::from(
+                "| Algorithme | Distribution | Taille | Graine | Runs | Min | Médiane | Max | Comparaisons | Échanges | Écritures |\n\
+                 |---|---|---:|---:|---:|---:|---:|---:|---:|---:|---:|\n",
+            );
+            for (row, result) in rows.iter().zip(results) {
+                out.push_str(&format!(
+                    "| {} | {} | {} | {} | {} | {:?} | {:?} | {:?} | {} | {} | {} |\n",
+                    row.algorithm,
+                    row.distribution,
+                    row.size,
+                    row.seed,
+                    row.runs,
+                    result.min,
+                    result.median,
+                    result.max,
+                    row.comparisons,
+                    row.swaps,
+                    row.writes,
+                ));
+            }
+            out
+        }
+    }
+}
+
+/// Relit un rapport CSV ou JSON produit par [`export`].
+pub fn load(text: &str, format: ReportFormat) -> Result<Vec<ReportRow>, String> {
+    match format {
+        ReportFormat::Json => serde_json::from_str(text).map_err(|e| format!("Rapport JSON invalide : {}", e)),
+        ReportFormat::Csv => {
+            let mut lines = text.lines().filter(|line| !line.trim().is_empty());
+            let header = parse_csv_record(lines.next().unwrap_or(""), ',');
+            // Les colonnes sont retrouvées par leur nom : leur ordre importe peu.
+            let positions = CSV_COLUMNS
+                .iter()
+                .map(|&name| {
+                    header
+                        .iter()
+                        .position(|h| h.trim() == name)
+                        .ok_or_else(|| format!("Colonne manquante dans le rapport CSV : '{}'", name))
+                })
+                .collect::<Result<Vec<usize>, String>>()?;
+
+            lines
+                .enumerate()
+                .map(|(i, line)| {
+                    let fields = parse_csv_record(line, ',');
+                    let values: Vec<&str> = positions
+                        .iter()
+                        .map(|&p| fields.get(p).map_or("", String::as_str))
+                        .collect();
+                    ReportRow::from_csv_values(&values).map_err(|e| format!("Ligne {} : {}", i + 2, e))
+                })
+                .collect()
+        }
+        ReportFormat::Markdown => Err("Un rapport Markdown ne peut pas être relu : utilisez csv ou json".to_string()),
+    }
+}
+
+// ====================================================================
+// COMPARAISON
+// ====================================================================
+
+/// Écart entre deux mesures du même algorithme, sur la même distribution
+/// et la même taille.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Comparison {
+    pub baseline: ReportRow,
+    pub current: ReportRow,
+    /// Variation du temps médian, en pourcentage (positif = plus lent).
+    pub time_change: f64,
+    /// Variation du nombre de comparaisons, en pourcentage.
+    pub comparisons_change: f64,
+    /// Une des deux variations dépasse le seuil.
+    pub regression: bool,
+}
+
+/// Apparie les lignes des deux rapports et signale celles dont le temps
+/// médian ou le nombre de comparaisons augmente de plus de `threshold` %.
+///
+/// Les lignes présentes dans un seul rapport sont ignorées.
+pub fn compare(baseline: &[ReportRow], current: &[ReportRow], threshold: f64) -> Vec<Comparison> {
+    let change = |before: u64, after: u64| {
+        if before == after {
+            0.0
+        } else {
+            (after as f64 - before as f64) / (before as f64).max(1.0) * 100.0
+        }
+    };
+
+    current
+        .iter()
+        .filter_map(|current| {
+            let baseline = baseline.iter().find(|b| b.key() == current.key())?;
+            let time_change = change(baseline.median_ns, current.median_ns);
+            let comparisons_change = change(baseline.comparisons, current.comparisons);
+            Some(Comparison {
+                baseline: baseline.clone(),
+                current: current.clone(),
+                time_change,
+                comparisons_change,
+                regression: time_change > threshold || comparisons_change > threshold,
+            })
+        })
+        .collect()
+}
+
+/// Met en forme une comparaison sous forme de tableau texte.
+pub fn format_comparison(comparisons: &[Comparison]) -> String {
+    const BORDER: &str = "+----------------+------------------+------------+--------------+--------------+----------+--------------+--------------+----------+------------+\n";
+
+    let mut out = String::new();
+    out.push_str(BORDER);
+    out.push_str("| Algorithme     | Distribution     | Taille     | Temps avant  | Temps après  | Écart    | Comp. avant  | Comp. après  | Écart    | Verdict    |\n");
+    out.push_str(BORDER);
+    for c in comparisons {
+        out.push_str(&format!(
+            "| {: <14} | {: <16} | {: <10} | {: <12} | {: <12} | {: >+7.1}% | {: <12} | {: <12} | {: >+7.1}% | {: <10} |\n",
+            c.current.algorithm,
+            c.current.distribution,
+            c.current.size,
+            format!("{:?}", std::time::Duration::from_nanos(c.baseline.median_ns)),
+            format!("{:?}", std::time::Duration::from_nanos(c.current.median_ns)),
+            c.time_change,
+            c.baseline.comparisons,
+            c.current.comparisons,
+            c.comparisons_change,
+            if c.regression { "RÉGRESSION" } else { "ok" },
+        ));
+    }
+    out.push_str(BORDER);
+    out
+}
@@ -0,0 +1,174 @@
+use std::cmp::Ordering;
+
+use crate::observer::{NoopObserver, SortObserver};
+use crate::sorts::{depth_budget, heap_sort_offset, partition_observed, quick_sort_observed, PivotStrategy};
+use crate::stats::SortStats;
+
+// ====================================================================
+// NTH ELEMENT (INTROSELECT)
+// ====================================================================
+
+/// Place en position `k` l'élément qui s'y trouverait après un tri, les plus
+/// petits à sa gauche et les plus grands à sa droite, puis le renvoie.
+///
+/// Sélection rapide (quickselect) sur `partition` : O(n) en moyenne. Au-delà
+/// de 2·log₂(n) partitions, la fenêtre restante est triée par tas, ce qui
+/// borne le pire cas à O(n log n) (introselect).
+///
+/// # Panics
+///
+/// Si `k >= arr.len()`.
+pub fn nth_element<T: Ord>(arr: &mut [T], k: usize) -> &T {
+    nth_element_by(arr, k, T::cmp)
+}
+
+/// Sélection du k-ième élément avec une fonction de comparaison personnalisée.
+pub fn nth_element_by<T, F>(arr: &mut [T], k: usize, mut compare: F) -> &T
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    nth_element_observed(arr, k, &mut compare, &mut NoopObserver);
+    &arr[k]
+}
+
+/// Sélection du k-ième élément selon une clé extraite de chaque élément.
+pub fn nth_element_by_key<T, K, F>(arr: &mut [T], k: usize, mut key: F) -> &T
+where
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    nth_element_by(arr, k, |a, b| key(a).cmp(&key(b)))
+}
+
+/// Comme [`nth_element`], en comptant les opérations effectuées.
+pub fn nth_element_instrumented<T: Ord>(arr: &mut [T], k: usize) -> SortStats {
+    let mut stats = SortStats::default();
+    nth_element_observed(arr, k, &mut T::cmp, &mut stats);
+    stats
+}
+
+pub(crate) fn nth_element_observed<T, F, O>(arr: &mut [T], k: usize, compare: &mut F, observer: &mut O)
+where
+    F: FnMut(&T, &T) -> Ordering,
+    O: SortObserver<T>,
+{
+    assert!(k < arr.len(), "index {} hors limites pour un tableau de {} éléments", k, arr.len());
+
+    // La fenêtre arr[lo..hi] contient toujours la position k.
+    let (mut lo, mut hi) = (0, arr.len());
+    let depth_limit = depth_budget(arr.len());
+    for depth in 0.. {
+        observer.recurse(depth);
+        let window = &mut arr[lo..hi];
+        if window.len() <= 1 {
+            return;
+        }
+        if depth >= depth_limit {
+            heap_sort_offset(window, compare, observer, lo);
+            return;
+        }
+
+        let pivot = lo + partition_observed(window, compare, observer, lo);
+        match k.cmp(&pivot) {
+            Ordering::Equal => return,
+            Ordering::Less => hi = pivot,
+            Ordering::Greater => lo = pivot + 1,
+        }
+    }
+}
+
+// ====================================================================
+// MEDIAN
+// ====================================================================
+
+/// Médiane (inférieure si la longueur est paire), `None` si le tableau est vide.
+/// Réordonne `arr` comme [`nth_element`].
+pub fn median<T: Ord>(arr: &mut [T]) -> Option<&T> {
+    median_by(arr, T::cmp)
+}
+
+/// Médiane avec une fonction de comparaison personnalisée.
+pub fn median_by<T, F>(arr: &mut [T], compare: F) -> Option<&T>
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    if arr.is_empty() {
+        return None;
+    }
+    let middle = (arr.len() - 1) / 2;
+    Some(nth_element_by(arr, middle, compare))
+}
+
+// ====================================================================
+// PARTIAL SORT / TOP K
+// ====================================================================
+
+/// Trie seulement les `k` plus petits éléments, placés dans `arr[..k]` ;
+/// le reste du tableau est dans un ordre quelconque. O(n + k log k) en moyenne.
+pub fn partial_sort<T: Ord>(arr: &mut [T], k: usize) {
+    partial_sort_by(arr, k, T::cmp);
+}
+
+/// Tri partiel avec une fonction de comparaison personnalisée.
+pub fn partial_sort_by<T, F>(arr: &mut [T], k: usize, mut compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    partial_sort_observed(arr, k, &mut compare, &mut NoopObserver);
+}
+
+/// Tri partiel selon une clé extraite de chaque élément.
+pub fn partial_sort_by_key<T, K, F>(arr: &mut [T], k: usize, mut key: F)
+where
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    partial_sort_by(arr, k, |a, b| key(a).cmp(&key(b)));
+}
+
+/// Comme [`partial_sort`], en comptant les opérations effectuées.
+pub fn partial_sort_instrumented<T: Ord>(arr: &mut [T], k: usize) -> SortStats {
+    let mut stats = SortStats::default();
+    partial_sort_observed(arr, k, &mut T::cmp, &mut stats);
+    stats
+}
+
+pub(crate) fn partial_sort_observed<T, F, O>(arr: &mut [T], k: usize, compare: &mut F, observer: &mut O)
+where
+    F: FnMut(&T, &T) -> Ordering,
+    O: SortObserver<T>,
+{
+    let k = k.min(arr.len());
+    if k == 0 {
+        return;
+    }
+    if k < arr.len() {
+        // arr[k - 1] est alors à sa place définitive, les plus petits avant lui.
+        nth_element_observed(arr, k - 1, compare, observer);
+    }
+    quick_sort_observed(&mut arr[..k], PivotStrategy::default(), compare, observer);
+}
+
+/// Les `k` plus grands éléments, du plus grand au plus petit, placés dans
+/// `arr[..k]` (moins si le tableau est plus court).
+pub fn top_k<T: Ord>(arr: &mut [T], k: usize) -> &[T] {
+    top_k_by(arr, k, T::cmp)
+}
+
+/// Les `k` plus grands éléments selon une fonction de comparaison personnalisée.
+pub fn top_k_by<T, F>(arr: &mut [T], k: usize, mut compare: F) -> &[T]
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    partial_sort_by(arr, k, |a, b| compare(b, a));
+    &arr[..k.min(arr.len())]
+}
+
+/// Les `k` éléments de plus grande clé.
+pub fn top_k_by_key<T, K, F>(arr: &mut [T], k: usize, mut key: F) -> &[T]
+where
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    top_k_by(arr, k, |a, b| key(a).cmp(&key(b)))
+}
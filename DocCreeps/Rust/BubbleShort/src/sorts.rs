@@ -0,0 +1,901 @@
+use std::cmp::Ordering;
+
+use crate::observer::{NoopObserver, SortObserver};
+
+// ====================================================================
+// BUBBLE SORT
+// ====================================================================
+
+/// Tri à bulles. O(n²), stable.
+pub fn bubble_sort<T: Ord>(arr: &mut [T]) {
+    bubble_sort_by(arr, T::cmp);
+}
+
+/// Tri à bulles avec une fonction de comparaison personnalisée.
+pub fn bubble_sort_by<T, F>(arr: &mut [T], mut compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    bubble_sort_observed(arr, &mut compare, &mut NoopObserver);
+}
+
+/// Tri à bulles selon une clé extraite de chaque élément.
+pub fn bubble_sort_by_key<T, K, F>(arr: &mut [T], mut key: F)
+where
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    bubble_sort_by(arr, |a, b| key(a).cmp(&key(b)));
+}
+
+pub(crate) fn bubble_sort_observed<T, F, O>(arr: &mut [T], compare: &mut F, observer: &mut O)
+where
+    F: FnMut(&T, &T) -> Ordering,
+    O: SortObserver<T>,
+{
+    let n = arr.len();
+    for i in 0..n {
+        for j in 0..(n - i - 1) {
+            observer.compare(j, j + 1);
+            if compare(&arr[j], &arr[j + 1]) == Ordering::Greater {
+                observer.swap(j, j + 1);
+                arr.swap(j, j + 1);
+            }
+        }
+    }
+}
+
+// ====================================================================
+// SELECTION SORT
+// ====================================================================
+
+/// Tri par sélection. O(n²), non stable.
+pub fn selection_sort<T: Ord>(arr: &mut [T]) {
+    selection_sort_by(arr, T::cmp);
+}
+
+/// Tri par sélection avec une fonction de comparaison personnalisée.
+pub fn selection_sort_by<T, F>(arr: &mut [T], mut compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    selection_sort_observed(arr, &mut compare, &mut NoopObserver);
+}
+
+/// Tri par sélection selon une clé extraite de chaque élément.
+pub fn selection_sort_by_key<T, K, F>(arr: &mut [T], mut key: F)
+where
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    selection_sort_by(arr, |a, b| key(a).cmp(&key(b)));
+}
+
+pub(crate) fn selection_sort_observed<T, F, O>(arr: &mut [T], compare: &mut F, observer: &mut O)
+where
+    F: FnMut(&T, &T) -> Ordering,
+    O: SortObserver<T>,
+{
+    let n = arr.len();
+    for i in 0..n {
+        let mut min_index = i;
+        for j in (i + 1)..n {
+            observer.compare(j, min_index);
+            if compare(&arr[j], &arr[min_index]) == Ordering::Less {
+                min_index = j;
+            }
+        }
+        observer.swap(i, min_index);
+        arr.swap(i, min_index);
+    }
+}
+
+// ====================================================================
+// INSERTION SORT
+// ====================================================================
+
+/// Tri par insertion. O(n²), stable, O(n) sur une entrée déjà triée.
+pub fn insertion_sort<T: Ord>(arr: &mut [T]) {
+    insertion_sort_by(arr, T::cmp);
+}
+
+/// Tri par insertion avec une fonction de comparaison personnalisée.
+///
+/// L'élément courant descend par échanges successifs, ce qui évite
+/// d'exiger `T: Copy` pour le sortir du tableau.
+pub fn insertion_sort_by<T, F>(arr: &mut [T], mut compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    insertion_sort_observed(arr, &mut compare, &mut NoopObserver);
+}
+
+/// Tri par insertion selon une clé extraite de chaque élément.
+pub fn insertion_sort_by_key<T, K, F>(arr: &mut [T], mut key: F)
+where
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    insertion_sort_by(arr, |a, b| key(a).cmp(&key(b)));
+}
+
+pub(crate) fn insertion_sort_observed<T, F, O>(arr: &mut [T], compare: &mut F, observer: &mut O)
+where
+    F: FnMut(&T, &T) -> Ordering,
+    O: SortObserver<T>,
+{
+    let n = arr.len();
+    for i in 1..n {
+        let mut j = i;
+        while j > 0 {
+            observer.compare(j - 1, j);
+            if compare(&arr[j - 1], &arr[j]) != Ordering::Greater {
+                break;
+            }
+            observer.swap(j - 1, j);
+            arr.swap(j - 1, j);
+            j -= 1;
+        }
+    }
+}
+
+// ====================================================================
+// MERGE SORT
+// ====================================================================
+
+/// Tri fusion. O(n log n), stable, O(n) de mémoire auxiliaire.
+pub fn merge_sort<T: Ord + Clone>(arr: &mut [T]) {
+    merge_sort_by(arr, T::cmp);
+}
+
+/// Tri fusion avec une fonction de comparaison personnalisée.
+pub fn merge_sort_by<T, F>(arr: &mut [T], mut compare: F)
+where
+    T: Clone,
+    F: FnMut(&T, &T) -> Ordering,
+{
+    merge_sort_observed(arr, &mut compare, &mut NoopObserver);
+}
+
+/// Tri fusion selon une clé extraite de chaque élément.
+pub fn merge_sort_by_key<T, K, F>(arr: &mut [T], mut key: F)
+where
+    T: Clone,
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    merge_sort_by(arr, |a, b| key(a).cmp(&key(b)));
+}
+
+pub(crate) fn merge_sort_observed<T, F, O>(arr: &mut [T], compare: &mut F, observer: &mut O)
+where
+    T: Clone,
+    F: FnMut(&T, &T) -> Ordering,
+    O: SortObserver<T>,
+{
+    merge_sort_rec(arr, compare, observer, 0, 0);
+}
+
+fn merge_sort_rec<T, F, O>(arr: &mut [T], compare: &mut F, observer: &mut O, offset: usize, depth: usize)
+where
+    T: Clone,
+    F: FnMut(&T, &T) -> Ordering,
+    O: SortObserver<T>,
+{
+    observer.recurse(depth);
+    let n = arr.len();
+    if n > 1 {
+        let mid = n / 2;
+        merge_sort_rec(&mut arr[0..mid], compare, observer, offset, depth + 1); // Sort left half
+        merge_sort_rec(&mut arr[mid..], compare, observer, offset + mid, depth + 1); // Sort right half
+        merge(arr, mid, compare, observer, offset); // Merge them back together
+    }
+}
+
+/// Fusionne les deux moitiés triées `arr[..mid]` et `arr[mid..]`.
+pub(crate) fn merge<T, F, O>(arr: &mut [T], mid: usize, compare: &mut F, observer: &mut O, offset: usize)
+where
+    T: Clone,
+    F: FnMut(&T, &T) -> Ordering,
+    O: SortObserver<T>,
+{
+    observer.allocate(mid);
+    let left = arr[0..mid].to_vec(); // Create a copy of the left half
+    observer.allocate(arr.len() - mid);
+    let right = arr[mid..].to_vec(); // Create a copy of the right half
+
+    let (mut i, mut j, mut k) = (0, 0, 0);
+
+    while i < left.len() && j < right.len() {
+        // `!= Greater` garde l'élément de gauche en cas d'égalité : tri stable.
+        observer.compare(offset + k, offset + mid + j);
+        if compare(&left[i], &right[j]) != Ordering::Greater {
+            arr[k] = left[i].clone();
+            i += 1;
+        } else {
+            arr[k] = right[j].clone();
+            j += 1;
+        }
+        observer.write(offset + k, &arr[k]);
+        k += 1;
+    }
+
+    while i < left.len() {
+        arr[k] = left[i].clone();
+        observer.write(offset + k, &arr[k]);
+        i += 1;
+        k += 1;
+    }
+
+    while j < right.len() {
+        arr[k] = right[j].clone();
+        observer.write(offset + k, &arr[k]);
+        j += 1;
+        k += 1;
+    }
+}
+
+// ====================================================================
+// QUICK SORT
+// ====================================================================
+
+/// Choix du pivot du tri rapide.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PivotStrategy {
+    /// Premier élément : dégénère sur une entrée déjà triée.
+    First,
+    /// Élément central.
+    Middle,
+    /// Position pseudo-aléatoire, tirée par un générateur interne déterministe
+    /// pour que les mesures restent reproductibles.
+    Random,
+    /// Médiane du premier, du central et du dernier élément.
+    MedianOfThree,
+    /// Médiane de trois médianes de trois (Tukey) ; médiane de trois
+    /// en dessous de `NINTHER_THRESHOLD` éléments.
+    #[default]
+    Ninther,
+}
+
+/// Taille à partir de laquelle le pivot « ninther » échantillonne neuf éléments.
+const NINTHER_THRESHOLD: usize = 128;
+
+impl PivotStrategy {
+    pub const ALL: [PivotStrategy; 5] = [
+        PivotStrategy::First,
+        PivotStrategy::Middle,
+        PivotStrategy::Random,
+        PivotStrategy::MedianOfThree,
+        PivotStrategy::Ninther,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            PivotStrategy::First => "first",
+            PivotStrategy::Middle => "middle",
+            PivotStrategy::Random => "random",
+            PivotStrategy::MedianOfThree => "median3",
+            PivotStrategy::Ninther => "ninther",
+        }
+    }
+}
+
+impl std::fmt::Display for PivotStrategy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl std::str::FromStr for PivotStrategy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim().to_lowercase();
+        PivotStrategy::ALL
+            .into_iter()
+            .find(|p| p.name() == s)
+            .ok_or_else(|| format!("Stratégie de pivot inconnue : '{}'", s))
+    }
+}
+
+/// Tri rapide façon introsort : pivot « ninther », partition en trois
+/// (inférieurs, égaux, supérieurs), repli sur le tri par tas au-delà de
+/// 2·log₂(n) niveaux de partition. O(n log n) dans tous les cas, non stable.
+pub fn quick_sort<T: Ord>(arr: &mut [T]) {
+    quick_sort_by(arr, T::cmp);
+}
+
+/// Tri rapide avec une fonction de comparaison personnalisée.
+pub fn quick_sort_by<T, F>(arr: &mut [T], compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    quick_sort_with_pivot_by(arr, PivotStrategy::default(), compare);
+}
+
+/// Tri rapide selon une clé extraite de chaque élément.
+pub fn quick_sort_by_key<T, K, F>(arr: &mut [T], mut key: F)
+where
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    quick_sort_by(arr, |a, b| key(a).cmp(&key(b)));
+}
+
+/// Tri rapide avec une stratégie de pivot donnée.
+pub fn quick_sort_with_pivot_by<T, F>(arr: &mut [T], pivot: PivotStrategy, mut compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    quick_sort_observed(arr, pivot, &mut compare, &mut NoopObserver);
+}
+
+pub(crate) fn quick_sort_observed<T, F, O>(arr: &mut [T], pivot: PivotStrategy, compare: &mut F, observer: &mut O)
+where
+    F: FnMut(&T, &T) -> Ordering,
+    O: SortObserver<T>,
+{
+    let mut rng = 0x9E37_79B9_7F4A_7C15 ^ arr.len() as u64;
+    let budget = depth_budget(arr.len());
+    quick_sort_rec(arr, pivot, compare, observer, &mut rng, 0, 0, budget);
+}
+
+/// Nombre de niveaux de partition tolérés avant le repli sur le tri par tas.
+pub(crate) fn depth_budget(len: usize) -> usize {
+    2 * (usize::BITS - len.leading_zeros()) as usize
+}
+
+#[allow(clippy::too_many_arguments)]
+fn quick_sort_rec<T, F, O>(
+    mut arr: &mut [T],
+    pivot: PivotStrategy,
+    compare: &mut F,
+    observer: &mut O,
+    rng: &mut u64,
+    mut offset: usize,
+    depth: usize,
+    mut budget: usize,
+) where
+    F: FnMut(&T, &T) -> Ordering,
+    O: SortObserver<T>,
+{
+    observer.recurse(depth);
+    while arr.len() > 1 {
+        if budget == 0 {
+            heap_sort_offset(arr, compare, observer, offset);
+            return;
+        }
+        budget -= 1;
+
+        let pivot_index = choose_pivot(arr, pivot, compare, observer, offset, rng);
+        let (lt, gt) = partition_three_way(arr, pivot_index, compare, observer, offset);
+        let (left, rest) = std::mem::take(&mut arr).split_at_mut(lt);
+        let right = &mut rest[gt - lt..];
+
+        // Appel récursif sur le plus petit côté, boucle sur le plus grand :
+        // la pile ne dépasse jamais log₂(n) niveaux.
+        if left.len() < right.len() {
+            quick_sort_rec(left, pivot, compare, observer, rng, offset, depth + 1, budget);
+            offset += gt;
+            arr = right;
+        } else {
+            quick_sort_rec(right, pivot, compare, observer, rng, offset + gt, depth + 1, budget);
+            arr = left;
+        }
+    }
+}
+
+/// Position du pivot dans `arr` (au moins deux éléments) selon la stratégie.
+pub(crate) fn choose_pivot<T, F, O>(
+    arr: &[T],
+    strategy: PivotStrategy,
+    compare: &mut F,
+    observer: &mut O,
+    offset: usize,
+    rng: &mut u64,
+) -> usize
+where
+    F: FnMut(&T, &T) -> Ordering,
+    O: SortObserver<T>,
+{
+    let n = arr.len();
+    let mut median = |a, b, c| median_of_three(arr, [a, b, c], compare, observer, offset);
+    match strategy {
+        PivotStrategy::First => 0,
+        PivotStrategy::Middle => n / 2,
+        PivotStrategy::Random => {
+            // xorshift64
+            *rng ^= *rng << 13;
+            *rng ^= *rng >> 7;
+            *rng ^= *rng << 17;
+            (*rng % n as u64) as usize
+        }
+        PivotStrategy::Ninther if n >= NINTHER_THRESHOLD => {
+            let (step, mid) = (n / 8, n / 2);
+            let low = median(0, step, 2 * step);
+            let middle = median(mid - step, mid, mid + step);
+            let high = median(n - 1 - 2 * step, n - 1 - step, n - 1);
+            median(low, middle, high)
+        }
+        PivotStrategy::MedianOfThree | PivotStrategy::Ninther => median(0, n / 2, n - 1),
+    }
+}
+
+/// Indice de la valeur médiane parmi trois positions de `arr`.
+fn median_of_three<T, F, O>(arr: &[T], [mut a, mut b, c]: [usize; 3], compare: &mut F, observer: &mut O, offset: usize) -> usize
+where
+    F: FnMut(&T, &T) -> Ordering,
+    O: SortObserver<T>,
+{
+    let mut less = |i: usize, j: usize| {
+        observer.compare(offset + i, offset + j);
+        compare(&arr[i], &arr[j]) == Ordering::Less
+    };
+    if less(b, a) {
+        std::mem::swap(&mut a, &mut b);
+    }
+    // a ≤ b : la médiane est b, sauf si c est plus petit
+    if less(c, b) {
+        return if less(c, a) { a } else { c };
+    }
+    b
+}
+
+/// Partition de Dijkstra (« drapeau hollandais ») autour de `arr[pivot_index]`.
+///
+/// Renvoie `(lt, gt)` : `arr[..lt]` est strictement inférieur au pivot,
+/// `arr[lt..gt]` lui est égal et `arr[gt..]` strictement supérieur. Les
+/// doublons du pivot ne sont ainsi jamais triés une seconde fois.
+pub(crate) fn partition_three_way<T, F, O>(
+    arr: &mut [T],
+    pivot_index: usize,
+    compare: &mut F,
+    observer: &mut O,
+    offset: usize,
+) -> (usize, usize)
+where
+    F: FnMut(&T, &T) -> Ordering,
+    O: SortObserver<T>,
+{
+    observer.swap(offset, offset + pivot_index);
+    arr.swap(0, pivot_index);
+
+    // arr[lt] est toujours égal au pivot
+    let (mut lt, mut i, mut gt) = (0, 1, arr.len());
+    while i < gt {
+        observer.compare(offset + i, offset + lt);
+        match compare(&arr[i], &arr[lt]) {
+            Ordering::Less => {
+                observer.swap(offset + lt, offset + i);
+                arr.swap(lt, i);
+                lt += 1;
+                i += 1;
+            }
+            Ordering::Greater => {
+                gt -= 1;
+                observer.swap(offset + i, offset + gt);
+                arr.swap(i, gt);
+            }
+            Ordering::Equal => i += 1,
+        }
+    }
+    (lt, gt)
+}
+
+/// Partitionne `arr` autour de son élément central et renvoie la position
+/// finale du pivot : tout ce qui est à gauche lui est strictement inférieur.
+/// Un tableau de moins de deux éléments est laissé tel quel et donne 0.
+pub fn partition<T, F>(arr: &mut [T], mut compare: F) -> usize
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    partition_observed(arr, &mut compare, &mut NoopObserver, 0)
+}
+
+pub(crate) fn partition_observed<T, F, O>(arr: &mut [T], compare: &mut F, observer: &mut O, offset: usize) -> usize
+where
+    F: FnMut(&T, &T) -> Ordering,
+    O: SortObserver<T>,
+{
+    if arr.len() < 2 {
+        return 0;
+    }
+    let last = arr.len() - 1;
+    let pivot_index = arr.len() / 2;
+    observer.swap(offset + pivot_index, offset + last);
+    arr.swap(pivot_index, last);
+    let mut store_index = 0;
+    for i in 0..last {
+        observer.compare(offset + i, offset + last);
+        if compare(&arr[i], &arr[last]) == Ordering::Less {
+            observer.swap(offset + i, offset + store_index);
+            arr.swap(i, store_index);
+            store_index += 1;
+        }
+    }
+    observer.swap(offset + store_index, offset + last);
+    arr.swap(store_index, last);
+    store_index
+}
+
+// ====================================================================
+// HEAP SORT
+// ====================================================================
+
+/// Tri par tas. O(n log n) dans tous les cas, en place, non stable.
+pub fn heap_sort<T: Ord>(arr: &mut [T]) {
+    heap_sort_by(arr, T::cmp);
+}
+
+/// Tri par tas avec une fonction de comparaison personnalisée.
+pub fn heap_sort_by<T, F>(arr: &mut [T], mut compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    heap_sort_observed(arr, &mut compare, &mut NoopObserver);
+}
+
+/// Tri par tas selon une clé extraite de chaque élément.
+pub fn heap_sort_by_key<T, K, F>(arr: &mut [T], mut key: F)
+where
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    heap_sort_by(arr, |a, b| key(a).cmp(&key(b)));
+}
+
+pub(crate) fn heap_sort_observed<T, F, O>(arr: &mut [T], compare: &mut F, observer: &mut O)
+where
+    F: FnMut(&T, &T) -> Ordering,
+    O: SortObserver<T>,
+{
+    heap_sort_offset(arr, compare, observer, 0);
+}
+
+/// Tri par tas d'une sous-tranche commençant à `offset` dans le tableau observé.
+pub(crate) fn heap_sort_offset<T, F, O>(arr: &mut [T], compare: &mut F, observer: &mut O, offset: usize)
+where
+    F: FnMut(&T, &T) -> Ordering,
+    O: SortObserver<T>,
+{
+    let n = arr.len();
+    // Construction du tas max
+    for start in (0..n / 2).rev() {
+        sift_down(arr, start, n, compare, observer, offset);
+    }
+    // Extraction successive du maximum vers la fin du tableau
+    for end in (1..n).rev() {
+        observer.swap(offset, offset + end);
+        arr.swap(0, end);
+        sift_down(arr, 0, end, compare, observer, offset);
+    }
+}
+
+/// Fait descendre `arr[root]` dans le tas `arr[..end]`.
+fn sift_down<T, F, O>(arr: &mut [T], mut root: usize, end: usize, compare: &mut F, observer: &mut O, offset: usize)
+where
+    F: FnMut(&T, &T) -> Ordering,
+    O: SortObserver<T>,
+{
+    loop {
+        let mut child = 2 * root + 1;
+        if child >= end {
+            break;
+        }
+        if child + 1 < end {
+            observer.compare(offset + child, offset + child + 1);
+            if compare(&arr[child], &arr[child + 1]) == Ordering::Less {
+                child += 1;
+            }
+        }
+        observer.compare(offset + root, offset + child);
+        if compare(&arr[root], &arr[child]) != Ordering::Less {
+            break;
+        }
+        observer.swap(offset + root, offset + child);
+        arr.swap(root, child);
+        root = child;
+    }
+}
+
+// ====================================================================
+// SHELL SORT
+// ====================================================================
+
+/// Suite d'écarts utilisée par le tri de Shell.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GapSequence {
+    /// Suite d'origine : n/2, n/4, ..., 1. O(n²) au pire.
+    Shell,
+    /// Knuth : 1, 4, 13, 40, ... ((3^k - 1) / 2). O(n^1.5).
+    Knuth,
+    /// Hibbard : 1, 3, 7, 15, ... (2^k - 1). O(n^1.5).
+    Hibbard,
+    /// Ciura : 1, 4, 10, 23, 57, 132, 301, 701, puis ×2.25. Meilleure en pratique.
+    #[default]
+    Ciura,
+}
+
+impl GapSequence {
+    pub const ALL: [GapSequence; 4] = [GapSequence::Shell, GapSequence::Knuth, GapSequence::Hibbard, GapSequence::Ciura];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            GapSequence::Shell => "shell",
+            GapSequence::Knuth => "knuth",
+            GapSequence::Hibbard => "hibbard",
+            GapSequence::Ciura => "ciura",
+        }
+    }
+
+    /// Écarts strictement inférieurs à `n`, du plus grand au plus petit (le dernier vaut 1).
+    pub fn gaps(self, n: usize) -> Vec<usize> {
+        let mut gaps = Vec::new();
+        match self {
+            GapSequence::Shell => {
+                let mut gap = n / 2;
+                while gap > 0 {
+                    gaps.push(gap);
+                    gap /= 2;
+                }
+                gaps.reverse();
+            }
+            GapSequence::Knuth => {
+                let mut gap = 1;
+                while gap < n.max(2) {
+                    gaps.push(gap);
+                    gap = 3 * gap + 1;
+                }
+            }
+            GapSequence::Hibbard => {
+                let mut gap = 1;
+                while gap < n.max(2) {
+                    gaps.push(gap);
+                    gap = 2 * gap + 1;
+                }
+            }
+            GapSequence::Ciura => {
+                const CIURA: [usize; 8] = [1, 4, 10, 23, 57, 132, 301, 701];
+                gaps.extend(CIURA.iter().copied().take_while(|&g| g < n.max(2)));
+                let mut gap = 701;
+                while gaps.last() == Some(&gap) {
+                    gap = gap * 9 / 4;
+                    if gap < n {
+                        gaps.push(gap);
+                    }
+                }
+            }
+        }
+        gaps.reverse();
+        gaps
+    }
+}
+
+impl std::fmt::Display for GapSequence {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl std::str::FromStr for GapSequence {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim().to_lowercase();
+        GapSequence::ALL
+            .into_iter()
+            .find(|g| g.name() == s)
+            .ok_or_else(|| format!("Suite d'écarts inconnue : '{}'", s))
+    }
+}
+
+/// Tri de Shell avec la suite de Ciura. Non stable.
+pub fn shell_sort<T: Ord>(arr: &mut [T]) {
+    shell_sort_by(arr, T::cmp);
+}
+
+/// Tri de Shell avec une fonction de comparaison personnalisée.
+pub fn shell_sort_by<T, F>(arr: &mut [T], compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    shell_sort_with_gaps_by(arr, GapSequence::default(), compare);
+}
+
+/// Tri de Shell selon une clé extraite de chaque élément.
+pub fn shell_sort_by_key<T, K, F>(arr: &mut [T], mut key: F)
+where
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    shell_sort_by(arr, |a, b| key(a).cmp(&key(b)));
+}
+
+/// Tri de Shell avec une suite d'écarts choisie.
+pub fn shell_sort_with_gaps_by<T, F>(arr: &mut [T], gaps: GapSequence, mut compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    shell_sort_observed(arr, gaps, &mut compare, &mut NoopObserver);
+}
+
+pub(crate) fn shell_sort_observed<T, F, O>(arr: &mut [T], gaps: GapSequence, compare: &mut F, observer: &mut O)
+where
+    F: FnMut(&T, &T) -> Ordering,
+    O: SortObserver<T>,
+{
+    let n = arr.len();
+    for gap in gaps.gaps(n) {
+        // Tri par insertion sur chaque sous-suite d'écart `gap`
+        for i in gap..n {
+            let mut j = i;
+            while j >= gap {
+                observer.compare(j - gap, j);
+                if compare(&arr[j - gap], &arr[j]) != Ordering::Greater {
+                    break;
+                }
+                observer.swap(j - gap, j);
+                arr.swap(j - gap, j);
+                j -= gap;
+            }
+        }
+    }
+}
+
+// ====================================================================
+// TIM SORT (HYBRIDE ADAPTATIF)
+// ====================================================================
+
+/// Taille minimale d'une suite avant fusion ; les suites plus courtes
+/// sont complétées par tri par insertion.
+const MIN_RUN: usize = 32;
+
+/// Tri hybride adaptatif inspiré de Timsort : détection des suites déjà
+/// triées, tri par insertion des petites suites puis fusions équilibrées.
+/// O(n) sur une entrée triée, O(n log n) au pire, stable.
+pub fn tim_sort<T: Ord + Clone>(arr: &mut [T]) {
+    tim_sort_by(arr, T::cmp);
+}
+
+/// Tri hybride avec une fonction de comparaison personnalisée.
+pub fn tim_sort_by<T, F>(arr: &mut [T], mut compare: F)
+where
+    T: Clone,
+    F: FnMut(&T, &T) -> Ordering,
+{
+    tim_sort_observed(arr, &mut compare, &mut NoopObserver);
+}
+
+/// Tri hybride selon une clé extraite de chaque élément.
+pub fn tim_sort_by_key<T, K, F>(arr: &mut [T], mut key: F)
+where
+    T: Clone,
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    tim_sort_by(arr, |a, b| key(a).cmp(&key(b)));
+}
+
+pub(crate) fn tim_sort_observed<T, F, O>(arr: &mut [T], compare: &mut F, observer: &mut O)
+where
+    T: Clone,
+    F: FnMut(&T, &T) -> Ordering,
+    O: SortObserver<T>,
+{
+    let n = arr.len();
+    // Pile des suites en attente de fusion : (début, longueur)
+    let mut runs: Vec<(usize, usize)> = Vec::new();
+    let mut start = 0;
+
+    while start < n {
+        let mut end = find_run(arr, start, compare, observer);
+        if end - start < MIN_RUN {
+            // Étend la suite puis la trie par insertion
+            let extended = (start + MIN_RUN).min(n);
+            insertion_sort_offset(&mut arr[start..extended], end - start, compare, observer, start);
+            end = extended;
+        }
+        runs.push((start, end - start));
+        collapse_runs(arr, &mut runs, compare, observer, false);
+        start = end;
+    }
+    collapse_runs(arr, &mut runs, compare, observer, true);
+}
+
+/// Renvoie la fin de la suite monotone qui commence à `start`.
+/// Une suite strictement décroissante est retournée sur place (ce qui
+/// préserve la stabilité).
+fn find_run<T, F, O>(arr: &mut [T], start: usize, compare: &mut F, observer: &mut O) -> usize
+where
+    F: FnMut(&T, &T) -> Ordering,
+    O: SortObserver<T>,
+{
+    let n = arr.len();
+    let mut end = start + 1;
+    if end == n {
+        return end;
+    }
+
+    observer.compare(end - 1, end);
+    if compare(&arr[end - 1], &arr[end]) == Ordering::Greater {
+        end += 1;
+        while end < n {
+            observer.compare(end - 1, end);
+            if compare(&arr[end - 1], &arr[end]) != Ordering::Greater {
+                break;
+            }
+            end += 1;
+        }
+        let (mut i, mut j) = (start, end - 1);
+        while i < j {
+            observer.swap(i, j);
+            arr.swap(i, j);
+            i += 1;
+            j -= 1;
+        }
+    } else {
+        end += 1;
+        while end < n {
+            observer.compare(end - 1, end);
+            if compare(&arr[end - 1], &arr[end]) == Ordering::Greater {
+                break;
+            }
+            end += 1;
+        }
+    }
+    end
+}
+
+/// Tri par insertion de `arr` dont les `sorted` premiers éléments sont déjà triés.
+fn insertion_sort_offset<T, F, O>(arr: &mut [T], sorted: usize, compare: &mut F, observer: &mut O, offset: usize)
+where
+    F: FnMut(&T, &T) -> Ordering,
+    O: SortObserver<T>,
+{
+    for i in sorted.max(1)..arr.len() {
+        let mut j = i;
+        while j > 0 {
+            observer.compare(offset + j - 1, offset + j);
+            if compare(&arr[j - 1], &arr[j]) != Ordering::Greater {
+                break;
+            }
+            observer.swap(offset + j - 1, offset + j);
+            arr.swap(j - 1, j);
+            j -= 1;
+        }
+    }
+}
+
+/// Fusionne les suites du sommet de la pile tant que les invariants de
+/// Timsort (`A > B + C` et `B > C`) ne sont pas respectés, ou toutes les
+/// suites si `force` est vrai.
+fn collapse_runs<T, F, O>(arr: &mut [T], runs: &mut Vec<(usize, usize)>, compare: &mut F, observer: &mut O, force: bool)
+where
+    T: Clone,
+    F: FnMut(&T, &T) -> Ordering,
+    O: SortObserver<T>,
+{
+    while runs.len() > 1 {
+        let k = runs.len();
+        let c = runs[k - 1].1;
+        let b = runs[k - 2].1;
+        let a = if k > 2 { runs[k - 3].1 } else { usize::MAX };
+
+        // Indice de la première des deux suites à fusionner
+        let at = if force || b <= c || a <= b + c {
+            if k > 2 && a < c {
+                k - 3
+            } else {
+                k - 2
+            }
+        } else {
+            break;
+        };
+
+        let (start, left_len) = runs[at];
+        let right_len = runs[at + 1].1;
+        merge(&mut arr[start..start + left_len + right_len], left_len, compare, observer, start);
+        runs[at] = (start, left_len + right_len);
+        runs.remove(at + 1);
+    }
+}
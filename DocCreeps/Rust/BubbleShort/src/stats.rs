@@ -0,0 +1,58 @@
+use std::fmt;
+
+use crate::observer::SortObserver;
+
+/// Coût algorithmique d'un tri, indépendant de la machine.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct SortStats {
+    /// Nombre de comparaisons entre éléments.
+    pub comparisons: u64,
+    /// Nombre d'échanges de deux éléments.
+    pub swaps: u64,
+    /// Nombre d'écritures simples (copies depuis un tampon auxiliaire).
+    pub writes: u64,
+    /// Profondeur de récursion maximale atteinte (0 pour un tri itératif).
+    pub max_depth: usize,
+    /// Nombre de tampons auxiliaires alloués.
+    pub allocations: u64,
+    /// Nombre total d'éléments copiés dans ces tampons.
+    pub allocated_elements: u64,
+}
+
+impl<T> SortObserver<T> for SortStats {
+    fn compare(&mut self, _i: usize, _j: usize) {
+        self.comparisons += 1;
+    }
+
+    fn swap(&mut self, _i: usize, _j: usize) {
+        self.swaps += 1;
+    }
+
+    fn write(&mut self, _i: usize, _value: &T) {
+        self.writes += 1;
+    }
+
+    fn recurse(&mut self, depth: usize) {
+        self.max_depth = self.max_depth.max(depth);
+    }
+
+    fn allocate(&mut self, len: usize) {
+        self.allocations += 1;
+        self.allocated_elements += len as u64;
+    }
+}
+
+impl fmt::Display for SortStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "comparaisons : {}, échanges : {}, écritures : {}, profondeur : {}, allocations : {} ({} éléments)",
+            self.comparisons,
+            self.swaps,
+            self.writes,
+            self.max_depth,
+            self.allocations,
+            self.allocated_elements
+        )
+    }
+}
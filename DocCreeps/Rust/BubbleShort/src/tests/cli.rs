@@ -0,0 +1,22 @@
+use super::*;
+
+fn bench_algorithms(args: &[&str]) -> Vec<Algorithm> {
+    let args: Vec<String> = args.iter().map(|a| a.to_string()).collect();
+    match parse_args(&args) {
+        Ok(Command::Bench(command)) => command.config.algorithms,
+        other => panic!("benchmark attendu : {:?}", other),
+    }
+}
+
+/// Les tris quadratiques ne sont écartés par défaut qu'au-delà du seuil.
+#[test]
+fn bench_default_algorithms() {
+    let limit = QUADRATIC_SIZE_LIMIT.to_string();
+    assert_eq!(bench_algorithms(&["bench", "--size", &limit]).len(), Algorithm::ALL.len());
+
+    let above = (QUADRATIC_SIZE_LIMIT + 1).to_string();
+    let algorithms = bench_algorithms(&["bench", "--size", &above]);
+    assert!(!algorithms.is_empty() && algorithms.iter().all(|a| !a.is_quadratic()));
+    assert_eq!(bench_algorithms(&["bench", "--size", &above, "--algo", "all"]).len(), Algorithm::ALL.len());
+    assert_eq!(bench_algorithms(&["bench", "--size", &above, "--algo", "bubble"]), [Algorithm::Bubble]);
+}
@@ -0,0 +1,564 @@
+use super::*;
+
+mod properties;
+
+type SortFn = fn(&mut [i32]);
+
+/// Tableau de test commun, avec doublons et valeurs négatives.
+fn sample() -> Vec<i32> {
+    vec![5, -3, 9, 0, 5, 12, -7, 3, 3, 1]
+}
+
+/// Vérifie que chaque algorithme trie des entiers comme `slice::sort`.
+#[test]
+fn sorts_match_std() {
+    let sorts: [(&str, SortFn); 10] = [
+        ("bubble", bubble_sort),
+        ("selection", selection_sort),
+        ("insertion", insertion_sort),
+        ("merge", merge_sort),
+        ("quick", quick_sort),
+        ("heap", heap_sort),
+        ("shell", shell_sort),
+        ("counting", counting_sort),
+        ("radix", radix_sort),
+        ("tim", tim_sort),
+    ];
+
+    let mut expected = sample();
+    expected.sort();
+
+    for (name, sort) in sorts {
+        let mut arr = sample();
+        sort(&mut arr);
+        assert_eq!(arr, expected, "{} sort ne trie pas correctement.", name);
+
+        let mut empty: Vec<i32> = Vec::new();
+        sort(&mut empty);
+        assert!(empty.is_empty(), "{} sort devrait accepter un tableau vide.", name);
+    }
+}
+
+/// Vérifie les variantes `_by` et `_by_key` sur des structures et des chaînes.
+#[test]
+fn sorts_by_and_by_key() {
+    #[derive(Debug, Clone, PartialEq)]
+    struct Joueur {
+        nom: &'static str,
+        score: u32,
+    }
+
+    let joueurs = vec![
+        Joueur { nom: "Alice", score: 30 },
+        Joueur { nom: "Bob", score: 10 },
+        Joueur { nom: "Chloé", score: 20 },
+    ];
+
+    let mut par_score = joueurs.clone();
+    quick_sort_by_key(&mut par_score, |j| j.score);
+    let noms: Vec<_> = par_score.iter().map(|j| j.nom).collect();
+    assert_eq!(noms, ["Bob", "Chloé", "Alice"]);
+
+    let mut par_nom_desc = joueurs;
+    merge_sort_by(&mut par_nom_desc, |a, b| b.nom.cmp(a.nom));
+    let noms: Vec<_> = par_nom_desc.iter().map(|j| j.nom).collect();
+    assert_eq!(noms, ["Chloé", "Bob", "Alice"]);
+
+    let mut mots = vec!["poire".to_string(), "ananas".to_string(), "kiwi".to_string()];
+    selection_sort(&mut mots);
+    assert_eq!(mots, ["ananas", "kiwi", "poire"]);
+
+    let mut flottants = vec![3.5, -0.5, 2.25, 0.0];
+    bubble_sort_by(&mut flottants, |a: &f64, b| a.total_cmp(b));
+    assert_eq!(flottants, [-0.5, 0.0, 2.25, 3.5]);
+}
+
+/// Vérifie que le benchmark est reproductible à graine égale.
+#[test]
+fn bench_same_seed_same_input() {
+    let config = BenchConfig {
+        algorithms: vec![Algorithm::Quick(PivotStrategy::default()), Algorithm::Merge],
+        distributions: vec![Distribution::Random],
+        size: 200,
+        runs: 3,
+        seed: 7,
+        counters: false,
+    };
+    let results = bench::run(&config);
+    assert_eq!(results.len(), 2);
+    for r in &results {
+        assert_eq!(r.runs, 3);
+        assert!(r.min <= r.median && r.median <= r.max);
+        assert_eq!(r.stats, SortStats::default());
+    }
+    assert!(bench::format_table(&results).contains("| quick:ninther "));
+}
+
+/// Vérifie les suites d'écarts du tri de Shell et leur usage.
+#[test]
+fn shell_gap_sequences() {
+    assert_eq!(GapSequence::Shell.gaps(20), [10, 5, 2, 1]);
+    assert_eq!(GapSequence::Knuth.gaps(50), [40, 13, 4, 1]);
+    assert_eq!(GapSequence::Hibbard.gaps(20), [15, 7, 3, 1]);
+    assert_eq!(GapSequence::Ciura.gaps(100), [57, 23, 10, 4, 1]);
+    assert_eq!(GapSequence::Ciura.gaps(2000), [1577, 701, 301, 132, 57, 23, 10, 4, 1]);
+    assert_eq!(GapSequence::Knuth.gaps(1), [1]);
+
+    let input = Distribution::Random.generate(500, 9);
+    let mut expected = input.clone();
+    expected.sort();
+    for gaps in GapSequence::ALL {
+        let mut arr = input.clone();
+        shell_sort_with_gaps_by(&mut arr, gaps, i32::cmp);
+        assert_eq!(arr, expected, "Shell sort ({}) ne trie pas correctement.", gaps);
+    }
+
+    assert_eq!("shell:knuth".parse(), Ok(Algorithm::Shell(GapSequence::Knuth)));
+    assert_eq!(Algorithm::Shell(GapSequence::Hibbard).to_string(), "shell:hibbard");
+}
+
+/// Vérifie les tris non comparatifs sur des clés signées et larges.
+#[test]
+fn counting_and_radix_sorts() {
+    let mut signed = vec![3i64, i64::MIN, -1, 0, i64::MAX, -1_000_000, 42];
+    let mut expected = signed.clone();
+    expected.sort();
+
+    let mut arr = signed.clone();
+    // Écart énorme : le tri par comptage doit basculer sur le radix.
+    counting_sort(&mut arr);
+    assert_eq!(arr, expected);
+    radix_sort(&mut signed);
+    assert_eq!(signed, expected);
+
+    // Stabilité des variantes par clé
+    let mut pairs = vec![(2u8, 'a'), (1, 'b'), (2, 'c'), (1, 'd'), (0, 'e')];
+    counting_sort_by_key(&mut pairs, |p| p.0);
+    assert_eq!(pairs, [(0, 'e'), (1, 'b'), (1, 'd'), (2, 'a'), (2, 'c')]);
+    let mut pairs = vec![(300u16, 'a'), (1, 'b'), (300, 'c'), (1, 'd')];
+    radix_sort_by_key(&mut pairs, |p| p.0);
+    assert_eq!(pairs, [(1, 'b'), (1, 'd'), (300, 'a'), (300, 'c')]);
+
+    assert!(!Algorithm::Radix.is_comparison());
+    assert!(Algorithm::Radix.sort_observed_by(&mut [2, 1], i32::cmp, &mut NoopObserver).is_err());
+}
+
+/// Vérifie le tri hybride sur des entrées composées de suites.
+#[test]
+fn tim_sort_runs() {
+    for distribution in Distribution::ALL {
+        let input = distribution.generate(1000, 5);
+        let mut expected = input.clone();
+        expected.sort();
+        let mut arr = input.clone();
+        tim_sort(&mut arr);
+        assert_eq!(arr, expected, "Tim sort échoue sur {}.", distribution);
+    }
+
+    // Adaptatif : une entrée déjà triée ne coûte que n-1 comparaisons.
+    let mut sorted = Distribution::Sorted.generate(1000, 0);
+    let stats = Algorithm::Tim.sort_instrumented(&mut sorted);
+    assert_eq!(stats.comparisons, 999);
+    assert_eq!(stats.writes, 0);
+
+    // Stable
+    let mut pairs: Vec<(i32, usize)> = Distribution::FewUnique { distinct: 5 }
+        .generate(300, 2)
+        .into_iter()
+        .enumerate()
+        .map(|(i, v)| (v, i))
+        .collect();
+    tim_sort_by_key(&mut pairs, |p| p.0);
+    assert!(pairs.windows(2).all(|w| w[0].0 < w[1].0 || (w[0].0 == w[1].0 && w[0].1 < w[1].1)));
+}
+
+/// Vérifie les compteurs du mode instrumenté sur des cas connus.
+#[test]
+fn instrumented_stats() {
+    // Bubble sort compare toujours n(n-1)/2 paires.
+    let mut arr = vec![4, 3, 2, 1];
+    let stats = Algorithm::Bubble.sort_instrumented(&mut arr);
+    assert_eq!(arr, [1, 2, 3, 4]);
+    assert_eq!(stats.comparisons, 6);
+    assert_eq!(stats.swaps, 6);
+    assert_eq!(stats.max_depth, 0);
+    assert_eq!(stats.allocations, 0);
+
+    // Insertion sort sur une entrée triée : n-1 comparaisons, aucun échange.
+    let mut arr: Vec<i32> = (0..100).collect();
+    let stats = Algorithm::Insertion.sort_instrumented(&mut arr);
+    assert_eq!(stats.comparisons, 99);
+    assert_eq!(stats.swaps, 0);
+
+    // Merge sort sur 8 éléments : profondeur log2(8), n écritures par niveau.
+    let mut arr = vec![8, 7, 6, 5, 4, 3, 2, 1];
+    let stats = Algorithm::Merge.sort_instrumented(&mut arr);
+    assert_eq!(arr, [1, 2, 3, 4, 5, 6, 7, 8]);
+    assert_eq!(stats.max_depth, 3);
+    assert_eq!(stats.writes, 24);
+    assert_eq!(stats.allocations, 14);
+    assert_eq!(stats.allocated_elements, 24);
+    assert_eq!(stats.swaps, 0);
+
+    // Quick sort ne copie rien mais échange.
+    let mut arr = vec![3, 1, 2, 5, 4];
+    let stats = Algorithm::Quick(PivotStrategy::default()).sort_instrumented(&mut arr);
+    assert_eq!(arr, [1, 2, 3, 4, 5]);
+    assert!(stats.swaps > 0);
+    assert_eq!(stats.writes, 0);
+    assert!(stats.max_depth >= 1);
+}
+
+/// Vérifie la forme et la reproductibilité des distributions d'entrée.
+#[test]
+fn distributions() {
+    for distribution in Distribution::ALL {
+        let a = distribution.generate(50, 42);
+        assert_eq!(a.len(), 50, "{} n'a pas la bonne taille.", distribution);
+        assert_eq!(a, distribution.generate(50, 42), "{} n'est pas reproductible.", distribution);
+        assert!(distribution.generate(0, 42).is_empty());
+        assert_eq!(distribution.to_string().parse::<Distribution>(), Ok(distribution));
+    }
+
+    assert_ne!(Distribution::Random.generate(50, 42), Distribution::Random.generate(50, 43));
+    assert_eq!(Distribution::Sorted.generate(4, 0), [0, 1, 2, 3]);
+    assert_eq!(Distribution::Reversed.generate(4, 0), [3, 2, 1, 0]);
+    assert_eq!(Distribution::OrganPipe.generate(7, 0), [0, 1, 2, 3, 2, 1, 0]);
+    assert_eq!(Distribution::Sawtooth { teeth: 2 }.generate(6, 0), [0, 1, 2, 0, 1, 2]);
+    assert_eq!(Distribution::AllEqual.generate(3, 0), [42, 42, 42]);
+
+    let few = Distribution::FewUnique { distinct: 3 }.generate(100, 1);
+    assert!(few.iter().all(|&v| (0..3).contains(&v)));
+
+    let mut nearly = Distribution::NearlySorted { swaps: 2 }.generate(100, 1);
+    let misplaced = nearly.iter().enumerate().filter(|&(i, &v)| v != i as i32).count();
+    assert!(misplaced <= 4);
+    nearly.sort();
+    assert_eq!(nearly, Distribution::Sorted.generate(100, 1));
+
+    assert_eq!("nearly-sorted:5".parse(), Ok(Distribution::NearlySorted { swaps: 5 }));
+    assert!("organ-pipe:3".parse::<Distribution>().is_err());
+    assert!("inconnue".parse::<Distribution>().is_err());
+}
+
+/// Vérifie que rejouer les événements enregistrés reproduit le tri.
+#[test]
+fn replay_events_sorts_input() {
+    let input = Distribution::Random.generate(64, 3);
+    let mut expected = input.clone();
+    expected.sort();
+
+    for algorithm in Algorithm::ALL {
+        let events = trace::record(algorithm, &input);
+        let mut replay = Replay::new(input.clone(), events);
+        assert!(!replay.is_empty(), "{} ne produit aucun événement.", algorithm);
+
+        while replay.step().is_some() {}
+        assert!(replay.is_finished());
+        assert_eq!(replay.data(), expected, "Le rejeu de {} ne trie pas l'entrée.", algorithm);
+
+        replay.reset();
+        assert_eq!(replay.position(), 0);
+        assert_eq!(replay.data(), input);
+    }
+}
+
+/// Vérifie les tris parallèles, y compris avec un seuil minimal.
+#[test]
+fn parallel_sorts() {
+    let input = Distribution::Random.generate(20_000, 11);
+    let mut expected = input.clone();
+    expected.sort();
+
+    for parallelism in [Parallelism::default(), Parallelism { threads: 2, cutoff: 2 }] {
+        let mut arr = input.clone();
+        par_merge_sort(&mut arr, parallelism);
+        assert_eq!(arr, expected);
+
+        let mut arr = input.clone();
+        par_quick_sort(&mut arr, parallelism);
+        assert_eq!(arr, expected);
+    }
+
+    // Stabilité du tri fusion parallèle
+    let mut pairs: Vec<(i32, usize)> = input.iter().map(|&v| v % 7).zip(0..).collect();
+    par_merge_sort_by_key(&mut pairs, Parallelism { threads: 4, cutoff: 64 }, |p| p.0);
+    assert!(pairs.windows(2).all(|w| w[0].0 < w[1].0 || (w[0].0 == w[1].0 && w[0].1 < w[1].1)));
+
+    // Le mode instrumenté compte les mêmes opérations que la version séquentielle.
+    let parallel = Algorithm::ParallelMerge(Parallelism::default());
+    assert_eq!(parallel.sequential(), Algorithm::Merge);
+    assert_eq!(
+        parallel.sort_instrumented(&mut input.clone()),
+        Algorithm::Merge.sort_instrumented(&mut input.clone())
+    );
+}
+
+/// Vérifie le tri de lignes et de CSV par colonnes.
+#[test]
+fn sort_records_by_columns() {
+    use records::{parse_csv_record, sort_stream};
+
+    assert_eq!("3nr".parse(), Ok(KeySpec { column: 3, numeric: true, reverse: true }));
+    assert_eq!("1".parse(), Ok(KeySpec { column: 1, numeric: false, reverse: false }));
+    assert!("0n".parse::<KeySpec>().is_err());
+    assert!("2x".parse::<KeySpec>().is_err());
+
+    assert_eq!(
+        parse_csv_record(r#"a,"b, c","d ""e""",,"#, ','),
+        ["a", "b, c", r#"d "e""#, "", ""]
+    );
+
+    let mut config = FileSortConfig {
+        algorithm: Algorithm::Merge,
+        format: InputFormat::Lines { delimiter: None },
+        keys: vec!["2n".parse().unwrap(), "1r".parse().unwrap()],
+        numeric: false,
+        reverse: false,
+        header: false,
+    };
+    let sort = |input: &str, config: &FileSortConfig| {
+        let mut out = Vec::new();
+        sort_stream(input.as_bytes(), &mut out, config).unwrap();
+        String::from_utf8(out).unwrap()
+    };
+
+    // Numérique sur la colonne 2, puis décroissant sur la colonne 1.
+    let input = "alice 10\nbob 9\nchloe 10\ndavid x\n";
+    assert_eq!(sort(input, &config), "david x\nbob 9\nchloe 10\nalice 10\n");
+
+    // Ligne entière, numérique et inversée
+    config.keys.clear();
+    config.numeric = true;
+    config.reverse = true;
+    assert_eq!(sort("2\n10\n1\n", &config), "10\n2\n1\n");
+
+    // CSV avec en-tête et champ multiligne ; la sortie conserve le texte d'origine.
+    config = FileSortConfig {
+        algorithm: Algorithm::Tim,
+        format: InputFormat::Csv { delimiter: ',' },
+        keys: vec!["2n".parse().unwrap()],
+        numeric: false,
+        reverse: false,
+        header: true,
+    };
+    let input = "nom,age\n\"Durand, Luc\",42\n\"Note\nsur deux lignes\",7\nZoé,19\n";
+    assert_eq!(
+        sort(input, &config),
+        "nom,age\n\"Note\nsur deux lignes\",7\nZoé,19\n\"Durand, Luc\",42\n"
+    );
+
+    // Les tris non comparatifs sont refusés.
+    config.algorithm = Algorithm::Radix;
+    assert!(sort_stream("1\n".as_bytes(), Vec::new(), &config).is_err());
+}
+
+/// Le tri externe, forcé à créer beaucoup de petites suites et plusieurs
+/// passes de fusion, produit la même sortie que le tri en mémoire.
+#[test]
+fn external_sort_matches_in_memory() {
+    use external::{external_sort, parse_memory, ExternalConfig};
+    use records::sort_stream;
+
+    assert_eq!(parse_memory("64M"), Ok(64 * 1024 * 1024));
+    assert_eq!(parse_memory("512"), Ok(512));
+    assert!(parse_memory("0").is_err());
+    assert!(parse_memory("12X").is_err());
+
+    let config = FileSortConfig {
+        algorithm: Algorithm::Merge,
+        format: InputFormat::Csv { delimiter: ',' },
+        keys: vec!["2n".parse().unwrap()],
+        numeric: false,
+        reverse: false,
+        header: true,
+    };
+    let mut input = String::from("id,valeur\n");
+    let values = Distribution::FewUnique { distinct: 20 }.generate(500, 7);
+    for (i, v) in values.iter().enumerate() {
+        input.push_str(&format!("\"ligne\n{}\",{}\n", i, v));
+    }
+
+    let mut expected = Vec::new();
+    sort_stream(input.as_bytes(), &mut expected, &config).unwrap();
+
+    let settings = ExternalConfig { memory_limit: 2048, fan_in: 3, ..ExternalConfig::default() };
+    let mut actual = Vec::new();
+    let stats = external_sort(input.as_bytes(), &mut actual, &config, &settings).unwrap();
+    assert_eq!(stats.records, 500);
+    assert!(stats.runs > 9, "{:?}", stats);
+    assert!(stats.merge_passes >= 2, "{:?}", stats);
+    assert_eq!(String::from_utf8(actual).unwrap(), String::from_utf8(expected).unwrap());
+
+    // Entrée vide : aucune suite, sortie vide
+    let mut out = Vec::new();
+    let stats = external_sort("".as_bytes(), &mut out, &config, &settings).unwrap();
+    assert_eq!((stats.records, stats.runs), (0, 0));
+    assert!(out.is_empty());
+}
+
+/// Les rapports CSV et JSON se relisent à l'identique, et la comparaison
+/// signale les hausses au-delà du seuil.
+#[test]
+fn bench_reports_round_trip_and_compare() {
+    use report::{compare, export, load};
+
+    let config = BenchConfig {
+        algorithms: vec![Algorithm::Insertion, Algorithm::Merge],
+        distributions: vec![Distribution::Random, Distribution::Sorted],
+        size: 64,
+        runs: 1,
+        seed: 3,
+        counters: true,
+    };
+    let results = bench::run(&config);
+    let rows: Vec<ReportRow> = results.iter().map(ReportRow::from).collect();
+    assert_eq!(rows[0].seed, 3);
+    assert_eq!(rows[3].algorithm, "merge");
+    assert_eq!(rows[3].distribution, "sorted");
+
+    for format in [ReportFormat::Csv, ReportFormat::Json] {
+        assert_eq!(load(&export(&results, format), format).unwrap(), rows, "{}", format);
+    }
+    let markdown = export(&results, ReportFormat::Markdown);
+    assert_eq!(markdown.lines().count(), 2 + rows.len());
+    assert!(load(&markdown, ReportFormat::Markdown).is_err());
+    assert_eq!(ReportFormat::from_path("out/bench.MD".as_ref()), Some(ReportFormat::Markdown));
+
+    let mut current = rows.clone();
+    current[0].median_ns = rows[0].median_ns * 2 + 1;
+    current[1].comparisons += rows[1].comparisons / 50; // +2 % : sous le seuil
+    current.pop(); // absente de la nouvelle mesure
+    let comparisons = compare(&rows, &current, 5.0);
+    assert_eq!(comparisons.len(), 3);
+    assert!(comparisons[0].regression);
+    assert!(!comparisons[1].regression && comparisons[1].comparisons_change > 0.0);
+    assert!(!comparisons[2].regression);
+}
+
+/// Les comptes de comparaisons, exacts, désignent la bonne classe.
+#[test]
+fn complexity_fits_comparison_counts() {
+    use complexity::{fit, plot, powers_of_two, sweep, ComplexityClass, ComplexityConfig, Metric};
+
+    assert_eq!(powers_of_two(3, 5), [8, 16, 32]);
+    let exact: Vec<(usize, f64)> = [16, 32, 64].iter().map(|&n| (n, 3.0 * (n * n) as f64)).collect();
+    let quadratic = fit(&exact, ComplexityClass::Quadratic).unwrap();
+    assert!((quadratic.coefficient - 3.0).abs() < 1e-9 && quadratic.error < 1e-9);
+    assert_eq!(fit(&[(16, 0.0), (32, 1.0)], ComplexityClass::Linear), None);
+
+    let mut config = ComplexityConfig {
+        algorithms: vec![Algorithm::Insertion, Algorithm::Merge, Algorithm::Counting],
+        distribution: Distribution::Reversed,
+        sizes: powers_of_two(5, 10),
+        runs: 1,
+        seed: 1,
+        metric: Metric::Comparisons,
+        time_limit: std::time::Duration::from_secs(60),
+    };
+    let best = |config: &ComplexityConfig| -> Vec<Option<ComplexityClass>> {
+        sweep(config).iter().map(|r| r.best().map(|f| f.class)).collect()
+    };
+    assert_eq!(
+        best(&config),
+        [Some(ComplexityClass::Quadratic), Some(ComplexityClass::Linearithmic), None]
+    );
+
+    // Sur une entrée déjà triée, le tri par insertion devient linéaire.
+    config.distribution = Distribution::Sorted;
+    config.algorithms = vec![Algorithm::Insertion];
+    assert_eq!(best(&config), [Some(ComplexityClass::Linear)]);
+
+    let results = sweep(&config);
+    let drawing = plot(&results, 40, 8);
+    assert_eq!(drawing.lines().count(), 8 + 2 + 1);
+    assert!(drawing.contains("* insertion (O(n))"));
+}
+
+/// Sélection du k-ième élément, médiane, tri partiel et top k.
+#[test]
+fn selection_algorithms() {
+    let mut sorted = sample();
+    sorted.sort();
+    for k in 0..sorted.len() {
+        let mut arr = sample();
+        assert_eq!(*nth_element(&mut arr, k), sorted[k]);
+        assert!(arr[..k].iter().all(|x| *x <= arr[k]) && arr[k + 1..].iter().all(|x| *x >= arr[k]));
+    }
+    assert_eq!(median(&mut sample()), Some(&3));
+    assert_eq!(median::<i32>(&mut []), None);
+    assert_eq!(partition(&mut [], i32::cmp), 0);
+    assert_eq!(partition(&mut [4], i32::cmp), 0);
+
+    let mut arr = sample();
+    partial_sort(&mut arr, 4);
+    assert_eq!(arr[..4], sorted[..4]);
+    assert_eq!(top_k(&mut sample(), 3), [12, 9, 5]);
+    assert_eq!(top_k(&mut sample(), 50).len(), sample().len());
+
+    let mut mots = vec!["pomme", "kiwi", "banane", "figue"];
+    assert_eq!(top_k_by_key(&mut mots, 1, |m| m.len()), ["banane"]);
+    assert_eq!(*nth_element_by(&mut mots, 0, |a, b| b.cmp(a)), "pomme");
+
+    // Sélection linéaire en moyenne : moins de comparaisons qu'un tri.
+    let n = 20_000;
+    let input = Distribution::Random.generate(n, 5);
+    let select = nth_element_instrumented(&mut input.clone(), n / 2);
+    let sort = Algorithm::Quick(PivotStrategy::default()).sort_instrumented(&mut input.clone());
+    assert!(select.comparisons < 6 * n as u64, "{}", select.comparisons);
+    assert!(select.comparisons < sort.comparisons, "{} / {}", select.comparisons, sort.comparisons);
+
+    // Que des doublons : chaque partition n'écarte qu'un élément, le repli
+    // sur le tri par tas borne la profondeur.
+    let mut equal = vec![7; 4096];
+    let stats = nth_element_instrumented(&mut equal, 2048);
+    assert!(stats.max_depth <= 2 * 13, "{}", stats.max_depth);
+    assert!(partial_sort_instrumented(&mut equal, 10).comparisons > 0);
+}
+
+/// Tri rapide : stratégies de pivot, partition en trois et repli sur le tri par tas.
+#[test]
+fn quick_sort_pivots_and_fallback() {
+    assert_eq!("quick:median3".parse(), Ok(Algorithm::Quick(PivotStrategy::MedianOfThree)));
+    assert_eq!("quick".parse(), Ok(Algorithm::Quick(PivotStrategy::Ninther)));
+    assert_eq!(Algorithm::Quick(PivotStrategy::First).to_string(), "quick:first");
+    assert!("quick:dernier".parse::<Algorithm>().is_err());
+
+    let n = 4096;
+    let log_n = 12;
+    for pivot in PivotStrategy::ALL {
+        for distribution in [Distribution::Sorted, Distribution::Reversed, Distribution::OrganPipe, Distribution::Random] {
+            let mut arr = distribution.generate(n, 9);
+            let mut expected = arr.clone();
+            expected.sort();
+            let stats = Algorithm::Quick(pivot).sort_instrumented(&mut arr);
+            assert_eq!(arr, expected, "{} sur {}", pivot, distribution);
+            // Récursion sur le plus petit côté : pile logarithmique.
+            assert!(stats.max_depth <= log_n, "{} sur {} : profondeur {}", pivot, distribution, stats.max_depth);
+            // Repli sur le tri par tas : jamais quadratique.
+            assert!(
+                stats.comparisons < 8 * (n * log_n) as u64,
+                "{} sur {} : {} comparaisons",
+                pivot,
+                distribution,
+                stats.comparisons
+            );
+        }
+    }
+
+    // Sans repli, le premier élément comme pivot serait quadratique sur une
+    // entrée triée ; la médiane de trois y fait mieux.
+    let sorted = Distribution::Sorted.generate(n, 0);
+    let first = Algorithm::Quick(PivotStrategy::First).sort_instrumented(&mut sorted.clone());
+    let median3 = Algorithm::Quick(PivotStrategy::MedianOfThree).sort_instrumented(&mut sorted.clone());
+    assert!(median3.comparisons < first.comparisons);
+    assert!(first.comparisons < (n * n / 8) as u64);
+
+    // Partition en trois : des valeurs toutes égales se trient en une passe.
+    let stats = Algorithm::Quick(PivotStrategy::default()).sort_instrumented(&mut vec![7; n]);
+    assert!(stats.comparisons < 2 * n as u64, "{}", stats.comparisons);
+
+    let mut paires = vec![(2, 'a'), (1, 'b'), (2, 'c'), (0, 'd')];
+    quick_sort_with_pivot_by(&mut paires, PivotStrategy::Random, |a, b| b.0.cmp(&a.0));
+    assert_eq!(paires.iter().map(|p| p.0).collect::<Vec<_>>(), [2, 2, 1, 0]);
+}
@@ -0,0 +1,169 @@
+//! Tests de propriétés : chaque algorithme est comparé à `slice::sort`
+//! sur des entrées générées aléatoirement par proptest.
+
+use std::collections::HashMap;
+
+use proptest::prelude::*;
+
+use super::*;
+
+/// Tous les algorithmes testés, y compris chaque suite d'écarts du tri de Shell,
+/// chaque stratégie de pivot du tri rapide
+/// et les tris parallèles avec un seuil minimal (pour forcer le découpage).
+fn algorithms() -> Vec<Algorithm> {
+    let tiny = Parallelism { threads: 2, cutoff: 2 };
+    let mut algorithms: Vec<Algorithm> = Algorithm::ALL.to_vec();
+    algorithms.extend(GapSequence::ALL.map(Algorithm::Shell));
+    algorithms.extend(PivotStrategy::ALL.map(Algorithm::Quick));
+    algorithms.push(Algorithm::ParallelMerge(tiny));
+    algorithms.push(Algorithm::ParallelQuick(tiny));
+    algorithms
+}
+
+/// Entrées variées : valeurs quelconques, nombreux doublons, valeurs extrêmes.
+fn inputs() -> impl Strategy<Value = Vec<i32>> {
+    prop_oneof![
+        prop::collection::vec(any::<i32>(), 0..200),
+        prop::collection::vec(0..4i32, 0..200),
+        prop::collection::vec(
+            prop_oneof![Just(i32::MIN), Just(i32::MAX), Just(0), Just(-1), any::<i32>()],
+            0..100
+        ),
+    ]
+}
+
+/// Vrai si `a` et `b` contiennent les mêmes éléments avec les mêmes multiplicités.
+fn is_permutation<T: std::hash::Hash + Eq>(a: &[T], b: &[T]) -> bool {
+    let mut counts: HashMap<&T, i64> = HashMap::new();
+    for x in a {
+        *counts.entry(x).or_default() += 1;
+    }
+    for x in b {
+        *counts.entry(x).or_default() -= 1;
+    }
+    a.len() == b.len() && counts.values().all(|&c| c == 0)
+}
+
+/// Trie des paires (clé, position d'origine) selon la clé seule.
+fn sort_pairs_by_key(algorithm: Algorithm, pairs: &mut [(i32, usize)]) {
+    match algorithm {
+        Algorithm::Counting => counting_sort_by_key(pairs, |p| p.0),
+        Algorithm::Radix => radix_sort_by_key(pairs, |p| p.0),
+        Algorithm::ParallelMerge(parallelism) => par_merge_sort_by_key(pairs, parallelism, |p| p.0),
+        Algorithm::ParallelQuick(parallelism) => par_quick_sort_by_key(pairs, parallelism, |p| p.0),
+        _ => algorithm
+            .sort_observed_by(pairs, |a, b| a.0.cmp(&b.0), &mut NoopObserver)
+            .unwrap(),
+    }
+}
+
+/// Cas limites explicites, en plus des entrées générées.
+#[test]
+fn edge_cases() {
+    let cases: Vec<Vec<i32>> = vec![
+        vec![],
+        vec![7],
+        vec![2, 1],
+        vec![1, 1, 1, 1],
+        vec![i32::MAX, i32::MIN, 0, i32::MAX, i32::MIN],
+        (0..100).rev().collect(),
+    ];
+
+    for algorithm in algorithms() {
+        for input in &cases {
+            let mut expected = input.clone();
+            expected.sort();
+            let mut arr = input.clone();
+            algorithm.sort(&mut arr);
+            assert_eq!(arr, expected, "{} échoue sur {:?}.", algorithm, input);
+        }
+    }
+}
+
+proptest! {
+    /// Le résultat est trié, identique à `slice::sort`, et une permutation de l'entrée.
+    #[test]
+    fn matches_std_sort(input in inputs()) {
+        let mut expected = input.clone();
+        expected.sort();
+
+        for algorithm in algorithms() {
+            let mut arr = input.clone();
+            algorithm.sort(&mut arr);
+            prop_assert!(is_permutation(&arr, &input), "{} perd ou duplique des éléments.", algorithm);
+            prop_assert!(arr.windows(2).all(|w| w[0] <= w[1]), "{} ne trie pas.", algorithm);
+            prop_assert_eq!(&arr, &expected, "{} diffère de slice::sort.", algorithm);
+        }
+    }
+
+    /// Le mode instrumenté et le rejeu des événements trient comme le mode normal.
+    #[test]
+    fn instrumented_and_replay_match(input in inputs()) {
+        let mut expected = input.clone();
+        expected.sort();
+
+        for algorithm in Algorithm::ALL {
+            let mut arr = input.clone();
+            algorithm.sort_instrumented(&mut arr);
+            prop_assert_eq!(&arr, &expected, "{} instrumenté diffère.", algorithm);
+
+            let mut replay = Replay::new(input.clone(), trace::record(algorithm, &input));
+            while replay.step().is_some() {}
+            prop_assert_eq!(replay.data(), &expected[..], "Le rejeu de {} diffère.", algorithm);
+        }
+    }
+
+    /// Les tris stables conservent l'ordre d'origine des clés égales.
+    #[test]
+    fn stable_sorts_are_stable(keys in prop::collection::vec(0..8i32, 0..200)) {
+        let pairs: Vec<(i32, usize)> = keys.iter().copied().zip(0..).collect();
+        let mut expected = pairs.clone();
+        expected.sort_by_key(|p| p.0); // slice::sort_by_key est stable
+
+        for algorithm in algorithms().into_iter().filter(|a| a.is_stable()) {
+            let mut arr = pairs.clone();
+            sort_pairs_by_key(algorithm, &mut arr);
+            prop_assert_eq!(&arr, &expected, "{} n'est pas stable.", algorithm);
+        }
+    }
+
+    /// Les tris instables trient tout de même correctement selon la clé.
+    #[test]
+    fn unstable_sorts_by_key(keys in prop::collection::vec(0..8i32, 0..200)) {
+        let pairs: Vec<(i32, usize)> = keys.iter().copied().zip(0..).collect();
+
+        for algorithm in algorithms().into_iter().filter(|a| !a.is_stable()) {
+            let mut arr = pairs.clone();
+            sort_pairs_by_key(algorithm, &mut arr);
+            prop_assert!(is_permutation(&arr, &pairs), "{} perd des éléments.", algorithm);
+            prop_assert!(arr.windows(2).all(|w| w[0].0 <= w[1].0), "{} ne trie pas par clé.", algorithm);
+        }
+    }
+}
+
+proptest! {
+    /// `nth_element`, `partial_sort` et `top_k` s'accordent avec un tri complet.
+    #[test]
+    fn selection_matches_sort(input in inputs(), k in 0..250usize) {
+        let mut sorted = input.clone();
+        sorted.sort();
+
+        if k < input.len() {
+            let mut arr = input.clone();
+            let value = *nth_element(&mut arr, k);
+            prop_assert_eq!(value, sorted[k]);
+            prop_assert!(arr[..k].iter().all(|&x| x <= value) && arr[k..].iter().all(|&x| x >= value));
+            prop_assert!(is_permutation(&arr, &input));
+        }
+
+        let mut arr = input.clone();
+        partial_sort(&mut arr, k);
+        let prefix = k.min(input.len());
+        prop_assert_eq!(&arr[..prefix], &sorted[..prefix]);
+        prop_assert!(is_permutation(&arr, &input));
+
+        let mut arr = input.clone();
+        let largest: Vec<i32> = sorted.iter().rev().take(k).copied().collect();
+        prop_assert_eq!(top_k(&mut arr, k), &largest[..]);
+    }
+}
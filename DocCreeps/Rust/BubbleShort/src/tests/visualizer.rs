@@ -0,0 +1,68 @@
+use super::*;
+
+fn config() -> VisualizerConfig {
+    VisualizerConfig {
+        algorithms: vec![Algorithm::Bubble, Algorithm::Quick(Default::default())],
+        distribution: Distribution::Reversed,
+        size: 8,
+        seed: 1,
+    }
+}
+
+/// Extrait le texte d'une ligne du buffer.
+fn line(buf: &Buffer, y: u16) -> String {
+    (0..buf.area.width)
+        .map(|x| buf.cell((x, y)).map_or(" ", |c| c.symbol()))
+        .collect()
+}
+
+/// Teste le rendu des panneaux côte à côte.
+#[test]
+fn render() {
+    let visualizer = Visualizer::new(&config());
+    let mut buf = Buffer::empty(Rect::new(0, 0, 100, 20));
+    (&visualizer).render(buf.area, &mut buf);
+
+    assert!(line(&buf, 0).contains("Visualisation des tris"), "Le titre est absent.");
+    assert!(line(&buf, 1).contains("Bubble Sort"), "Le panneau Bubble Sort est absent.");
+    assert!(line(&buf, 1).contains("Quick Sort"), "Le panneau Quick Sort est absent.");
+    assert!(line(&buf, 19).contains("<Espace>"), "Les instructions sont absentes.");
+}
+
+/// Teste pause, pas à pas, vitesse et remise à zéro.
+#[test]
+fn handle_key_event() {
+    let mut visualizer = Visualizer::new(&config());
+    assert!(visualizer.paused, "La visualisation doit démarrer en pause.");
+
+    visualizer.tick();
+    assert_eq!(visualizer.panels[0].replay.position(), 0, "Aucun pas ne doit être joué en pause.");
+
+    visualizer.handle_key_event(KeyCode::Right.into());
+    assert_eq!(visualizer.panels[0].replay.position(), 1);
+    assert_eq!(visualizer.panels[0].comparisons, 1);
+
+    visualizer.handle_key_event(KeyCode::Char('+').into());
+    visualizer.handle_key_event(KeyCode::Char('+').into());
+    assert_eq!(visualizer.speed, 4);
+
+    visualizer.handle_key_event(KeyCode::Char(' ').into());
+    assert!(!visualizer.paused);
+    visualizer.tick();
+    assert_eq!(visualizer.panels[0].replay.position(), 5);
+
+    // Jouer jusqu'au bout : chaque panneau doit finir trié.
+    visualizer.speed = MAX_SPEED;
+    visualizer.tick();
+    for panel in &visualizer.panels {
+        assert!(panel.replay.is_finished());
+        assert_eq!(panel.replay.data(), [0, 1, 2, 3, 4, 5, 6, 7]);
+    }
+
+    visualizer.handle_key_event(KeyCode::Char('r').into());
+    assert_eq!(visualizer.panels[1].replay.position(), 0);
+    assert_eq!(visualizer.panels[1].swaps, 0);
+
+    visualizer.handle_key_event(KeyCode::Char('q').into());
+    assert!(visualizer.exit);
+}
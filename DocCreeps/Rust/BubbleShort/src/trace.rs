@@ -0,0 +1,118 @@
+use crate::algorithm::Algorithm;
+use crate::observer::SortObserver;
+use crate::radix::RadixKey;
+
+/// Opération élémentaire d'un tri, rejouable sur une copie de l'entrée.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SortEvent<T> {
+    /// Comparaison des positions `i` et `j`.
+    Compare(usize, usize),
+    /// Échange des positions `i` et `j`.
+    Swap(usize, usize),
+    /// Écriture d'une valeur à une position.
+    Write(usize, T),
+}
+
+impl<T> SortEvent<T> {
+    /// Positions touchées par l'événement, pour la mise en évidence.
+    pub fn indices(&self) -> (usize, Option<usize>) {
+        match *self {
+            SortEvent::Compare(i, j) | SortEvent::Swap(i, j) => (i, Some(j)),
+            SortEvent::Write(i, _) => (i, None),
+        }
+    }
+}
+
+/// Observateur qui enregistre la suite complète des événements d'un tri.
+#[derive(Debug, Clone)]
+pub struct EventRecorder<T> {
+    pub events: Vec<SortEvent<T>>,
+}
+
+impl<T> Default for EventRecorder<T> {
+    fn default() -> Self {
+        Self { events: Vec::new() }
+    }
+}
+
+impl<T: Clone> SortObserver<T> for EventRecorder<T> {
+    fn compare(&mut self, i: usize, j: usize) {
+        self.events.push(SortEvent::Compare(i, j));
+    }
+
+    fn swap(&mut self, i: usize, j: usize) {
+        self.events.push(SortEvent::Swap(i, j));
+    }
+
+    fn write(&mut self, i: usize, value: &T) {
+        self.events.push(SortEvent::Write(i, value.clone()));
+    }
+}
+
+/// Trie une copie de `input` et renvoie les événements produits.
+pub fn record<T: Ord + RadixKey>(algorithm: Algorithm, input: &[T]) -> Vec<SortEvent<T>> {
+    let mut recorder = EventRecorder::default();
+    algorithm.sort_observed(&mut input.to_vec(), &mut recorder);
+    recorder.events
+}
+
+/// Rejoue pas à pas une suite d'événements sur une copie de l'entrée.
+#[derive(Debug, Clone)]
+pub struct Replay<T> {
+    input: Vec<T>,
+    data: Vec<T>,
+    events: Vec<SortEvent<T>>,
+    position: usize,
+}
+
+impl<T: Clone> Replay<T> {
+    pub fn new(input: Vec<T>, events: Vec<SortEvent<T>>) -> Self {
+        Self { data: input.clone(), input, events, position: 0 }
+    }
+
+    /// Revient à l'état initial.
+    pub fn reset(&mut self) {
+        self.data.clone_from(&self.input);
+        self.position = 0;
+    }
+
+    /// État courant du tableau.
+    pub fn data(&self) -> &[T] {
+        &self.data
+    }
+
+    /// Nombre d'événements déjà appliqués.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Nombre total d'événements.
+    pub fn len(&self) -> usize {
+        self.events.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.position >= self.events.len()
+    }
+
+    /// Dernier événement appliqué.
+    pub fn current(&self) -> Option<&SortEvent<T>> {
+        self.position.checked_sub(1).map(|i| &self.events[i])
+    }
+
+    /// Applique l'événement suivant et le renvoie.
+    pub fn step(&mut self) -> Option<&SortEvent<T>> {
+        let event = self.events.get(self.position)?;
+        match event {
+            SortEvent::Compare(_, _) => {}
+            SortEvent::Swap(i, j) => self.data.swap(*i, *j),
+            SortEvent::Write(i, value) => self.data[*i] = value.clone(),
+        }
+        self.position += 1;
+        Some(event)
+    }
+}
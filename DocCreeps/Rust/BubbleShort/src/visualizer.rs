@@ -0,0 +1,280 @@
+use std::io;
+use std::time::{Duration, Instant};
+
+use bubble_short::{trace, Algorithm, Distribution, Replay, SortEvent};
+use crossterm::{
+    event::{self, Event, KeyCode, KeyEvent, KeyEventKind},
+    execute,
+    terminal::{self, EnterAlternateScreen, LeaveAlternateScreen},
+};
+use ratatui::{
+    buffer::Buffer,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Style, Stylize},
+    symbols::border,
+    text::Line,
+    widgets::{Bar, BarChart, BarGroup, Block, Widget},
+};
+
+/// Durée d'une image de l'animation.
+const TICK: Duration = Duration::from_millis(50);
+
+/// Vitesse maximale, en événements appliqués par image.
+const MAX_SPEED: usize = 4096;
+
+/// Paramètres de la visualisation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct VisualizerConfig {
+    pub algorithms: Vec<Algorithm>,
+    pub distribution: Distribution,
+    pub size: usize,
+    pub seed: u64,
+}
+
+// Fonction d'initialisation et de restauration du terminal
+fn init() -> io::Result<ratatui::Terminal<ratatui::backend::CrosstermBackend<io::Stdout>>> {
+    execute!(io::stdout(), EnterAlternateScreen)?;
+    terminal::enable_raw_mode()?;
+    ratatui::Terminal::new(ratatui::backend::CrosstermBackend::new(io::stdout()))
+}
+
+fn restore() -> io::Result<()> {
+    execute!(io::stdout(), LeaveAlternateScreen)?;
+    terminal::disable_raw_mode()?;
+    Ok(())
+}
+
+/// Lance la visualisation dans le terminal jusqu'à ce que l'utilisateur quitte.
+pub fn run(config: &VisualizerConfig) -> io::Result<()> {
+    let mut terminal = init()?;
+    let app_result = Visualizer::new(config).run(&mut terminal);
+    restore()?;
+    app_result
+}
+
+/// Un algorithme en cours d'animation.
+#[derive(Debug)]
+struct Panel {
+    algorithm: Algorithm,
+    replay: Replay<i32>,
+    comparisons: u64,
+    swaps: u64,
+    writes: u64,
+}
+
+impl Panel {
+    fn new(algorithm: Algorithm, input: &[i32]) -> Self {
+        let events = trace::record(algorithm, input);
+        Self {
+            algorithm,
+            replay: Replay::new(input.to_vec(), events),
+            comparisons: 0,
+            swaps: 0,
+            writes: 0,
+        }
+    }
+
+    fn step(&mut self) {
+        match self.replay.step() {
+            Some(SortEvent::Compare(_, _)) => self.comparisons += 1,
+            Some(SortEvent::Swap(_, _)) => self.swaps += 1,
+            Some(SortEvent::Write(_, _)) => self.writes += 1,
+            None => {}
+        }
+    }
+
+    fn reset(&mut self) {
+        self.replay.reset();
+        self.comparisons = 0;
+        self.swaps = 0;
+        self.writes = 0;
+    }
+}
+
+/// État de la visualisation : plusieurs algorithmes côte à côte sur la même entrée.
+#[derive(Debug)]
+struct Visualizer {
+    panels: Vec<Panel>,
+    max_value: i32,
+    min_value: i32,
+    paused: bool,
+    speed: usize,
+    exit: bool,
+}
+
+impl Visualizer {
+    fn new(config: &VisualizerConfig) -> Self {
+        let input = config.distribution.generate(config.size, config.seed);
+        Self {
+            panels: config.algorithms.iter().map(|&a| Panel::new(a, &input)).collect(),
+            max_value: input.iter().copied().max().unwrap_or(0),
+            min_value: input.iter().copied().min().unwrap_or(0),
+            paused: true,
+            speed: 1,
+            exit: false,
+        }
+    }
+
+    fn run(&mut self, terminal: &mut ratatui::Terminal<ratatui::backend::CrosstermBackend<io::Stdout>>) -> io::Result<()> {
+        let mut last_tick = Instant::now();
+        while !self.exit {
+            terminal.draw(|frame| frame.render_widget(&*self, frame.area()))?;
+
+            let timeout = TICK.saturating_sub(last_tick.elapsed());
+            if event::poll(timeout)? {
+                if let Event::Key(key_event) = event::read()? {
+                    if key_event.kind == KeyEventKind::Press {
+                        self.handle_key_event(key_event);
+                    }
+                }
+            }
+            if last_tick.elapsed() >= TICK {
+                self.tick();
+                last_tick = Instant::now();
+            }
+        }
+        Ok(())
+    }
+
+    fn handle_key_event(&mut self, key_event: KeyEvent) {
+        match key_event.code {
+            KeyCode::Char('q') | KeyCode::Esc => self.exit = true,
+            KeyCode::Char(' ') => self.paused = !self.paused,
+            KeyCode::Right | KeyCode::Char('s') => {
+                self.paused = true;
+                self.advance(1);
+            }
+            KeyCode::Char('+') | KeyCode::Up => self.speed = (self.speed * 2).min(MAX_SPEED),
+            KeyCode::Char('-') | KeyCode::Down => self.speed = (self.speed / 2).max(1),
+            KeyCode::Char('r') => {
+                self.panels.iter_mut().for_each(Panel::reset);
+                self.paused = true;
+            }
+            _ => {}
+        }
+    }
+
+    fn tick(&mut self) {
+        if !self.paused {
+            self.advance(self.speed);
+        }
+    }
+
+    fn advance(&mut self, steps: usize) {
+        for panel in &mut self.panels {
+            for _ in 0..steps {
+                panel.step();
+            }
+        }
+    }
+
+    fn render_panel(&self, panel: &Panel, area: Rect, buf: &mut Buffer) {
+        let status = if panel.replay.is_finished() {
+            " trié ".green().bold()
+        } else {
+            format!(" {}/{} ", panel.replay.position(), panel.replay.len()).into()
+        };
+        let counters = Line::from(format!(
+            " comparaisons {} | échanges {} | écritures {} ",
+            panel.comparisons, panel.swaps, panel.writes
+        ));
+        let block = Block::bordered()
+            .title(Line::from(format!(" {} ", panel.algorithm.label()).bold()))
+            .title(Line::from(status).right_aligned())
+            .title_bottom(counters.centered());
+
+        let inner = block.inner(area);
+        block.render(area, buf);
+
+        let data = panel.replay.data();
+        if data.is_empty() || inner.width == 0 {
+            return;
+        }
+
+        let (active, other) = match panel.replay.current() {
+            Some(event) if !panel.replay.is_finished() => {
+                let color = match event {
+                    SortEvent::Compare(_, _) => Color::Yellow,
+                    SortEvent::Swap(_, _) | SortEvent::Write(_, _) => Color::Red,
+                };
+                let (i, j) = event.indices();
+                (Some((i, color)), j.map(|j| (j, color)))
+            }
+            _ => (None, None),
+        };
+
+        let bars: Vec<Bar> = data
+            .iter()
+            .enumerate()
+            .map(|(index, &value)| {
+                let color = [active, other]
+                    .into_iter()
+                    .flatten()
+                    .find(|&(i, _)| i == index)
+                    .map_or(Color::Cyan, |(_, c)| c);
+                Bar::default()
+                    // +1 pour que la plus petite valeur reste visible
+                    .value((value - self.min_value) as u64 + 1)
+                    .text_value(String::new())
+                    .style(Style::default().fg(color))
+            })
+            .collect();
+
+        let n = data.len().min(u16::MAX as usize) as u16;
+        let gap = if inner.width >= n.saturating_mul(2) { 1 } else { 0 };
+        let bar_width = ((inner.width + gap) / n).saturating_sub(gap).max(1);
+
+        BarChart::default()
+            .data(BarGroup::default().bars(&bars))
+            .bar_width(bar_width)
+            .bar_gap(gap)
+            .max((self.max_value - self.min_value) as u64 + 1)
+            .render(inner, buf);
+    }
+}
+
+// Implémentation du trait Widget pour &Visualizer
+impl Widget for &Visualizer {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let title = Line::from(" Visualisation des tris ".bold());
+        let state = if self.paused { "pause" } else { "lecture" };
+        let instructions = Line::from(vec![
+            format!(" [{} x{}] ", state, self.speed).into(),
+            " Lecture/Pause ".into(),
+            "<Espace>".blue().bold(),
+            " Pas ".into(),
+            "<Droite>".blue().bold(),
+            " Vitesse ".into(),
+            "<+/->".blue().bold(),
+            " Recommencer ".into(),
+            "<R>".blue().bold(),
+            " Quitter ".into(),
+            "<Q> ".blue().bold(),
+        ]);
+
+        let block = Block::bordered()
+            .title(title.centered())
+            .title_bottom(instructions.centered())
+            .border_set(border::THICK);
+        let inner = block.inner(area);
+        block.render(area, buf);
+
+        if self.panels.is_empty() {
+            return;
+        }
+
+        let chunks = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints(vec![Constraint::Ratio(1, self.panels.len() as u32); self.panels.len()])
+            .split(inner);
+
+        for (panel, chunk) in self.panels.iter().zip(chunks.iter()) {
+            self.render_panel(panel, *chunk, buf);
+        }
+    }
+}
+
+// Déclaration du module de tests
+#[cfg(test)]
+#[path = "tests/visualizer.rs"]
+mod tests;
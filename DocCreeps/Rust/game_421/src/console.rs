@@ -0,0 +1,514 @@
+use std::io::{self, BufRead, Write};
+use std::sync::Arc;
+
+use game_421::des::SourceDes;
+use game_421::historique::maintenant;
+use game_421::ia::{Comptage, Conseil, Niveau, Ordinateur, Solveur};
+use game_421::journal::{Coup, Journal};
+use game_421::partie::{choisir_gardes, Etat, Evenement, Partie, Resultat, Score, MAX_LANCERS};
+use game_421::regles::Regles;
+use game_421::reseau::{envoyer, recevoir, Message, Requete};
+use game_421::table::{EvenementTable, Joueur, Phase, Table, JETONS};
+
+/// Interface console du jeu : lit les réponses du joueur sur `entree` et
+/// affiche le déroulement de la partie sur `sortie`.
+pub struct Console<R, W> {
+    entree: R,
+    sortie: W,
+    regles: Arc<Regles>,
+    /// Pour les conseils demandés par le joueur (`?`) ; son [`Comptage`] suit
+    /// le mode de jeu de la session.
+    solveur: Solveur,
+}
+
+impl<R: BufRead, W: Write> Console<R, W> {
+    /// Console jouant avec la table de règles traditionnelle.
+    pub fn new(entree: R, sortie: W) -> Self {
+        let regles = Arc::new(Regles::traditionnelles());
+        Console {
+            entree,
+            sortie,
+            solveur: Solveur::new(Arc::clone(&regles), Comptage::Meilleure),
+            regles,
+        }
+    }
+
+    /// Remplace la table de règles (variante maison).
+    pub fn avec_regles(mut self, regles: Regles) -> Self {
+        self.regles = Arc::new(regles);
+        self.solveur = Solveur::new(Arc::clone(&self.regles), Comptage::Meilleure);
+        self
+    }
+
+    /// Conseils selon la combinaison qui compte : la meilleure en solo, la
+    /// dernière à plusieurs.
+    fn compter(&mut self, comptage: Comptage) {
+        if self.solveur.comptage() != comptage {
+            self.solveur = Solveur::new(Arc::clone(&self.regles), comptage);
+        }
+    }
+
+    /// Enchaîne les parties jusqu'à ce que le joueur arrête, affiche les scores
+    /// et renvoie le journal de la session, scores compris.
+    pub fn session(&mut self, source: &mut dyn SourceDes, graine: u64) -> io::Result<Journal> {
+        self.compter(Comptage::Meilleure);
+        writeln!(self.sortie, "Bienvenue dans le jeu du 421 !")?;
+        writeln!(self.sortie, "Graine : {}", graine)?;
+
+        let max_lancers = self.demander_nombre_lancers()?;
+        writeln!(self.sortie, "Vous avez choisi {} lancer(s) par partie.", max_lancers)?;
+
+        let mut journal = Journal::new(graine, &self.regles);
+        let mut score_total = 0;
+        let mut numero_partie = 1;
+
+        loop {
+            writeln!(self.sortie, "\nPartie {} :", numero_partie)?;
+            let debut = maintenant();
+            journal.nouvelle_partie(max_lancers);
+            let resultat = self.jouer_partie(max_lancers, source, &mut journal)?;
+            score_total += resultat.points;
+            journal.terminer_partie(Score::new(numero_partie, &resultat, debut, maintenant()));
+
+            writeln!(self.sortie, "Points de cette partie : {}", resultat.points)?;
+            writeln!(self.sortie, "Score total : {}", score_total)?;
+
+            if !self.demander_rejouer()? {
+                break;
+            }
+
+            numero_partie += 1;
+        }
+
+        self.afficher_tableau_scores(&journal.scores, graine)?;
+        writeln!(self.sortie, "Merci d'avoir joué !")?;
+        Ok(journal)
+    }
+
+    /// Partie à plusieurs joueurs sur le même écran, jusqu'à ce que l'un
+    /// d'eux ait tous les jetons. Les `humains` jouent d'abord, suivis d'un
+    /// joueur ordinateur par niveau de `ordinateurs`.
+    pub fn session_table(
+        &mut self,
+        source: &mut dyn SourceDes,
+        graine: u64,
+        humains: usize,
+        ordinateurs: &[Niveau],
+    ) -> io::Result<()> {
+        self.compter(Comptage::Derniere);
+        let nombre = humains + ordinateurs.len();
+        writeln!(self.sortie, "Bienvenue dans le jeu du 421 à {} joueurs !", nombre)?;
+        writeln!(self.sortie, "Graine : {}", graine)?;
+
+        let mut noms = Vec::new();
+        for i in 1..=humains {
+            write!(self.sortie, "Nom du joueur {} : ", i)?;
+            match self.lire_texte()?.as_str() {
+                "" => noms.push(format!("Joueur {}", i)),
+                nom => noms.push(nom.to_string()),
+            }
+        }
+        // Les ordinateurs tirent leurs choix aléatoires d'une graine dérivée de la session.
+        let mut joueurs_ia: Vec<Option<Ordinateur>> = vec![None; humains];
+        for (i, &niveau) in ordinateurs.iter().enumerate() {
+            noms.push(format!("Ordinateur {} ({})", i + 1, niveau));
+            let graine_ia = graine.wrapping_add(i as u64 + 1);
+            joueurs_ia.push(Some(Ordinateur::new(niveau, Arc::clone(&self.regles), Comptage::Derniere, graine_ia)));
+        }
+
+        let max_lancers = self.demander_nombre_lancers()?;
+        let mut table = Table::new(noms, max_lancers, Arc::clone(&self.regles)).map_err(io::Error::other)?;
+        writeln!(self.sortie, "\nCharge : {} jetons dans le pot.", JETONS)?;
+
+        while !table.terminee() {
+            let joueur = &table.joueurs()[table.joueur_courant()];
+            writeln!(
+                self.sortie,
+                "\nÀ {} de jouer ({} jeton(s), {} lancer(s) au plus) :",
+                joueur.nom,
+                joueur.jetons,
+                table.partie().max_lancers()
+            )?;
+
+            let mut evenements = table.lancer(source).map_err(io::Error::other)?;
+            loop {
+                for evenement in &evenements {
+                    self.afficher_evenement_table(table.joueurs(), evenement)?;
+                    if let EvenementTable::Manche { .. } = evenement {
+                        self.afficher_jetons(table.pot(), table.joueurs())?;
+                    }
+                }
+                if table.terminee() || table.partie().etat() != Etat::Decision {
+                    break;
+                }
+
+                let courant = table.joueur_courant();
+                evenements = match &mut joueurs_ia[courant] {
+                    Some(ordinateur) => {
+                        let choix = ordinateur.decider(table.partie());
+                        self.afficher_choix(&table.joueurs()[courant].nom, &table.partie().des(), choix)?;
+                        match choix {
+                            Some(gardes) => table.garder(gardes).and_then(|()| table.lancer(source)),
+                            None => table.arreter(),
+                        }
+                    }
+                    None if self.demander_relancer(table.partie().des(), table.partie().des(), table.partie().lancers_restants())? => {
+                        let (des, restants) = (table.partie().des(), table.partie().lancers_restants());
+                        self.demander_gardes(des, des, restants, |gardes| table.garder(gardes))?;
+                        table.lancer(source)
+                    }
+                    None => table.arreter(),
+                }
+                .map_err(io::Error::other)?;
+            }
+        }
+
+        writeln!(self.sortie, "Graine : {} (rejouez cette session avec --seed {})", graine, graine)?;
+        writeln!(self.sortie, "Merci d'avoir joué !")
+    }
+
+    /// Client d'une table en réseau : le serveur lance les dés, la console
+    /// n'envoie que les décisions du joueur. S'arrête à la fin de la partie.
+    pub fn session_reseau<E: BufRead, S: Write>(
+        &mut self,
+        mut reception: E,
+        mut envoi: S,
+        nom: &str,
+    ) -> io::Result<()> {
+        self.compter(Comptage::Derniere);
+        envoyer(&mut envoi, &Requete::Rejoindre { nom: nom.to_string() })?;
+        let mut moi = 0;
+        let mut joueurs: Vec<Joueur> = Vec::new();
+
+        loop {
+            let message = recevoir(&mut reception)?.ok_or_else(|| {
+                io::Error::new(io::ErrorKind::UnexpectedEof, "Connexion fermée par le serveur")
+            })?;
+            match message {
+                Message::Bienvenue { joueur, attendus } => {
+                    moi = joueur;
+                    writeln!(self.sortie, "Connecté à la table ({} joueurs attendus).", attendus)?;
+                }
+                Message::Debut { joueurs: noms, max_lancers } => {
+                    writeln!(
+                        self.sortie,
+                        "La partie commence : {} ({} lancer(s) au plus).",
+                        noms.join(", "),
+                        max_lancers
+                    )?;
+                    joueurs = noms.into_iter().map(|nom| Joueur { nom, jetons: 0 }).collect();
+                    writeln!(self.sortie, "\nCharge : {} jetons dans le pot.", JETONS)?;
+                }
+                Message::Tour { joueur, max_lancers } => {
+                    let nom = if joueur == moi { "vous" } else { nom_joueur(&joueurs, joueur)? };
+                    writeln!(self.sortie, "\nÀ {} de jouer ({} lancer(s) au plus) :", nom, max_lancers)?;
+                }
+                Message::Evenement { evenement } => {
+                    for joueur in joueurs_cites(&evenement) {
+                        nom_joueur(&joueurs, joueur)?;
+                    }
+                    self.afficher_evenement_table(&joueurs, &evenement)?;
+                    if let EvenementTable::FinDePartie { .. } = evenement {
+                        return writeln!(self.sortie, "Merci d'avoir joué !");
+                    }
+                }
+                Message::Decision { des, lancers_restants } => {
+                    let requete = if self.demander_relancer(des, des, lancers_restants)? {
+                        // Le serveur vérifie le choix et redemande s'il est refusé.
+                        let mut choix = [false; 3];
+                        self.demander_gardes(des, des, lancers_restants, |gardes| {
+                            choix = gardes;
+                            Ok(())
+                        })?;
+                        Requete::Lancer { gardes: choix }
+                    } else {
+                        Requete::Passer
+                    };
+                    envoyer(&mut envoi, &requete)?;
+                }
+                Message::Scores { pot, joueurs: jetons } => {
+                    joueurs = jetons;
+                    self.afficher_jetons(pot, &joueurs)?;
+                }
+                Message::Erreur { message } => writeln!(self.sortie, "{}", message)?,
+                Message::Abandon { joueur } => {
+                    let nom = nom_joueur(&joueurs, joueur)?;
+                    return writeln!(self.sortie, "{} a quitté la table : partie interrompue.", nom);
+                }
+            }
+        }
+    }
+
+    fn afficher_evenement_table(&mut self, joueurs: &[Joueur], evenement: &EvenementTable) -> io::Result<()> {
+        let nom = |joueur: usize| &joueurs[joueur].nom;
+        match *evenement {
+            EvenementTable::Jeu(evenement) => self.afficher_evenement(&evenement),
+            EvenementTable::TourTermine { joueur, des, .. } => writeln!(
+                self.sortie,
+                "{} garde {} {} {} ({})",
+                nom(joueur),
+                des[0],
+                des[1],
+                des[2],
+                self.regles.nom(&des)
+            ),
+            EvenementTable::Manche { phase, gagnant, perdant, jetons } => {
+                let origine = match phase {
+                    Phase::Charge => "du pot".to_string(),
+                    Phase::Decharge => format!("de {}", nom(gagnant)),
+                };
+                writeln!(
+                    self.sortie,
+                    "{} gagne la manche ; {} reçoit {} jeton(s) {}.",
+                    nom(gagnant),
+                    nom(perdant),
+                    jetons,
+                    origine
+                )
+            }
+            EvenementTable::Decharge => writeln!(self.sortie, "\nLe pot est vide : place à la décharge !"),
+            EvenementTable::FinDePartie { perdant } => {
+                writeln!(self.sortie, "\n{} a tous les jetons et perd la partie !", nom(perdant))
+            }
+        }
+    }
+
+    fn afficher_jetons(&mut self, pot: u32, joueurs: &[Joueur]) -> io::Result<()> {
+        let jetons: Vec<String> = joueurs.iter().map(|j| format!("{} {}", j.nom, j.jetons)).collect();
+        writeln!(self.sortie, "Pot : {} | {}", pot, jetons.join(" | "))
+    }
+
+    /// Lit une ligne ; la fin de l'entrée est une erreur, pour ne pas boucler
+    /// indéfiniment sur une question.
+    fn lire_texte(&mut self) -> io::Result<String> {
+        self.sortie.flush()?;
+        let mut ligne = String::new();
+        if self.entree.read_line(&mut ligne)? == 0 {
+            return Err(io::Error::new(io::ErrorKind::UnexpectedEof, "Fin de l'entrée"));
+        }
+        Ok(ligne.trim().to_string())
+    }
+
+    /// Réponse du joueur, en minuscules.
+    fn lire_ligne(&mut self) -> io::Result<String> {
+        Ok(self.lire_texte()?.to_lowercase())
+    }
+
+    fn demander_nombre_lancers(&mut self) -> io::Result<u32> {
+        loop {
+            write!(self.sortie, "Combien de lancers voulez-vous par partie ? (1-{}) : ", MAX_LANCERS)?;
+
+            match self.lire_ligne()?.parse() {
+                Ok(n) if (1..=MAX_LANCERS).contains(&n) => return Ok(n),
+                _ => writeln!(self.sortie, "Veuillez entrer un nombre entre 1 et {}.", MAX_LANCERS)?,
+            }
+        }
+    }
+
+    /// Joue une partie en demandant après chaque lancer s'il faut relancer, et
+    /// note chaque coup dans le journal.
+    fn jouer_partie(
+        &mut self,
+        max_lancers: u32,
+        source: &mut dyn SourceDes,
+        journal: &mut Journal,
+    ) -> io::Result<Resultat> {
+        let mut partie = Partie::avec_regles(max_lancers, Arc::clone(&self.regles)).map_err(io::Error::other)?;
+        let mut evenements = partie.lancer(source).map_err(io::Error::other)?;
+
+        loop {
+            journal.noter_evenements(&evenements);
+            for evenement in &evenements {
+                self.afficher_evenement(evenement)?;
+            }
+            if partie.etat() == Etat::Terminee {
+                break;
+            }
+
+            let (des, restants) = (partie.des(), partie.lancers_restants());
+            let meilleur = partie.meilleur().map_or(des, |m| m.des);
+            evenements = if self.demander_relancer(des, meilleur, restants)? {
+                self.demander_gardes(des, meilleur, restants, |gardes| partie.garder(gardes))?;
+                journal.noter(Coup::Garder { gardes: partie.gardes() });
+                partie.lancer(source)
+            } else {
+                journal.noter(Coup::Arreter);
+                partie.arreter().map(|evenement| vec![evenement])
+            }
+            .map_err(io::Error::other)?;
+        }
+
+        Ok(partie.resultat().expect("partie terminée"))
+    }
+
+    fn afficher_evenement(&mut self, evenement: &Evenement) -> io::Result<()> {
+        match evenement {
+            Evenement::DesLances { lancer, des, points } => {
+                writeln!(self.sortie, "Résultat du lancer : {} {} {}", des[0], des[1], des[2])?;
+                writeln!(self.sortie, "Lancer {} : {} points ({})", lancer, points, self.regles.nom(des))
+            }
+            Evenement::Quatre21 => writeln!(self.sortie, "Bravo ! Vous avez obtenu 421 !"),
+            Evenement::PartieTerminee(resultat) => writeln!(
+                self.sortie,
+                "Votre meilleur score : {} points avec la combinaison {}",
+                resultat.points,
+                resultat.combinaison()
+            ),
+        }
+    }
+
+    /// Demande s'il faut relancer ; `?` affiche le conseil du solveur, pour les
+    /// dés en main et la meilleure combinaison obtenue (`meilleur`).
+    fn demander_relancer(&mut self, des: [u8; 3], meilleur: [u8; 3], restants: u32) -> io::Result<bool> {
+        loop {
+            write!(
+                self.sortie,
+                "Voulez-vous relancer ? (o/n, ? pour un conseil) [Il vous reste {} lancer(s)] : ",
+                restants
+            )?;
+            match self.lire_ligne()?.as_str() {
+                "?" => {
+                    let conseil = self.solveur.conseil(&des, &meilleur, restants);
+                    let conseil = formater_conseil(&des, &conseil, self.solveur.comptage());
+                    writeln!(self.sortie, "Conseil : {}", conseil)?;
+                }
+                reponse => return Ok(reponse == "o"),
+            }
+        }
+    }
+
+    fn afficher_choix(&mut self, nom: &str, des: &[u8; 3], choix: Option<[bool; 3]>) -> io::Result<()> {
+        match choix {
+            None => writeln!(self.sortie, "{} s'arrête.", nom),
+            Some(gardes) => match valeurs_gardees(des, gardes).as_str() {
+                "" => writeln!(self.sortie, "{} relance les trois dés.", nom),
+                valeurs => writeln!(self.sortie, "{} conserve {} et relance.", nom, valeurs),
+            },
+        }
+    }
+
+    /// Demande les valeurs des dés à garder (« 4 2 ») ; une ligne vide relance
+    /// tout et `?` affiche le conseil du solveur.
+    fn demander_gardes(
+        &mut self,
+        des: [u8; 3],
+        meilleur: [u8; 3],
+        restants: u32,
+        mut garder: impl FnMut([bool; 3]) -> Result<(), String>,
+    ) -> io::Result<()> {
+        loop {
+            write!(self.sortie, "Dés à garder (ex. : 4 2, vide pour tout relancer, ? pour un conseil) : ")?;
+            let ligne = self.lire_ligne()?;
+            if ligne == "?" {
+                let conseil = self.solveur.conseil(&des, &meilleur, restants);
+                let conseil = formater_conseil(&des, &conseil, self.solveur.comptage());
+                writeln!(self.sortie, "Conseil : {}", conseil)?;
+                continue;
+            }
+            let valeurs: Result<Vec<u8>, _> = ligne.split_whitespace().map(str::parse).collect();
+            let choix = valeurs
+                .map_err(|_| format!("Valeurs invalides : '{}'", ligne))
+                .and_then(|valeurs| choisir_gardes(&des, &valeurs))
+                .and_then(&mut garder);
+            match choix {
+                Ok(()) => return Ok(()),
+                Err(message) => writeln!(self.sortie, "{}", message)?,
+            }
+        }
+    }
+
+    fn demander_rejouer(&mut self) -> io::Result<bool> {
+        write!(self.sortie, "Voulez-vous jouer une autre partie ? (o/n) : ")?;
+        Ok(self.lire_ligne()? == "o")
+    }
+
+    /// Tableau des scores d'une session, avec totaux et statistiques.
+    pub fn afficher_tableau_scores(&mut self, scores: &[Score], graine: u64) -> io::Result<()> {
+        if scores.is_empty() {
+            return writeln!(self.sortie, "\nAucune partie jouée (graine {}).", graine);
+        }
+        let score_total: u32 = scores.iter().map(|s| s.points).sum();
+        let total_lancers: u32 = scores.iter().map(|s| s.lancers).sum();
+        let out = &mut self.sortie;
+        writeln!(out, "\nTableau des scores :")?;
+        writeln!(out, "+---------+--------+-------------+----------+")?;
+        writeln!(out, "| Partie  | Points | Combinaison | Lancers  |")?;
+        writeln!(out, "+---------+--------+-------------+----------+")?;
+
+        for score in scores {
+            writeln!(
+                out,
+                "| {: <7} | {: <6} | {: <11} | {: <8} |",
+                score.partie, score.points, score.combinaison, score.lancers
+            )?;
+        }
+
+        writeln!(out, "+---------+--------+-------------+----------+")?;
+        writeln!(out, "| Total   | {: <6} |             | {: <8} |", score_total, total_lancers)?;
+        writeln!(out, "+---------+--------+-------------+----------+")?;
+        writeln!(out, "Graine : {} (rejouez cette session avec --seed {})", graine, graine)?;
+
+        let nombre_parties = scores.len() as f32;
+        let moyenne_points = score_total as f32 / nombre_parties;
+        let moyenne_lancers = total_lancers as f32 / nombre_parties;
+
+        writeln!(out, "\nStatistiques :")?;
+        writeln!(out, "Nombre de parties : {}", scores.len())?;
+        writeln!(out, "Moyenne de points par partie : {:.2}", moyenne_points)?;
+        writeln!(out, "Moyenne de lancers par partie : {:.2}", moyenne_lancers)?;
+
+        if let Some(meilleur_score) = scores.iter().max_by_key(|s| s.points) {
+            writeln!(
+                out,
+                "Meilleur score : {} points (partie {}, obtenu au lancer {})",
+                meilleur_score.points, meilleur_score.partie, meilleur_score.lancer_record
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Valeurs des dés gardés, séparées par des espaces : `"4 2"`.
+fn valeurs_gardees(des: &[u8; 3], gardes: [bool; 3]) -> String {
+    let valeurs: Vec<String> = (0..3).filter(|&i| gardes[i]).map(|i| des[i].to_string()).collect();
+    valeurs.join(" ")
+}
+
+/// Conseil du solveur, en points pour une partie solo et en jetons à la table.
+fn formater_conseil(des: &[u8; 3], conseil: &Conseil, comptage: Comptage) -> String {
+    let unite = match comptage {
+        Comptage::Meilleure => "points",
+        Comptage::Derniere => "jetons",
+    };
+    match conseil.gardes {
+        None => format!("s'arrêter ({:.2} {})", conseil.esperance, unite),
+        Some(gardes) => match valeurs_gardees(des, gardes).as_str() {
+            "" => format!("relancer les trois dés (espérance {:.2} {})", conseil.esperance, unite),
+            valeurs => format!(
+                "garder {} et relancer le reste (espérance {:.2} {})",
+                valeurs, conseil.esperance, unite
+            ),
+        },
+    }
+}
+
+/// Nom d'un joueur désigné par le serveur ; un indice inconnu est une erreur
+/// de protocole.
+fn nom_joueur(joueurs: &[Joueur], joueur: usize) -> io::Result<&str> {
+    joueurs.get(joueur).map(|j| j.nom.as_str()).ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("Erreur de protocole : joueur {} inconnu du serveur", joueur),
+        )
+    })
+}
+
+/// Joueurs désignés par un événement de table.
+fn joueurs_cites(evenement: &EvenementTable) -> Vec<usize> {
+    match *evenement {
+        EvenementTable::TourTermine { joueur, .. } => vec![joueur],
+        EvenementTable::Manche { gagnant, perdant, .. } => vec![gagnant, perdant],
+        EvenementTable::FinDePartie { perdant } => vec![perdant],
+        EvenementTable::Jeu(_) | EvenementTable::Decharge => Vec::new(),
+    }
+}
@@ -0,0 +1,64 @@
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+/// Source des valeurs de dés : le moteur de jeu ne tire jamais le hasard
+/// lui-même, ce qui permet de rejouer une partie à l'identique.
+pub trait SourceDes {
+    /// Renvoie la valeur d'un dé, entre 1 et 6.
+    fn lancer_de(&mut self) -> u8;
+}
+
+/// Dés pseudo-aléatoires issus d'une graine : même graine, mêmes lancers.
+pub struct DesAleatoires {
+    graine: u64,
+    rng: StdRng,
+}
+
+impl DesAleatoires {
+    pub fn new(graine: u64) -> Self {
+        DesAleatoires {
+            graine,
+            rng: StdRng::seed_from_u64(graine),
+        }
+    }
+
+    /// Graine d'origine, à passer à `--seed` pour rejouer la session.
+    pub fn graine(&self) -> u64 {
+        self.graine
+    }
+}
+
+impl SourceDes for DesAleatoires {
+    fn lancer_de(&mut self) -> u8 {
+        self.rng.gen_range(1..=6)
+    }
+}
+
+/// Dés scriptés : renvoie une suite de valeurs fixée à l'avance (tests, démonstrations).
+pub struct DesScriptes {
+    valeurs: Vec<u8>,
+    position: usize,
+}
+
+impl DesScriptes {
+    /// Les valeurs doivent toutes être comprises entre 1 et 6.
+    pub fn new(valeurs: Vec<u8>) -> Self {
+        assert!(
+            valeurs.iter().all(|v| (1..=6).contains(v)),
+            "Les valeurs d'un dé vont de 1 à 6 : {:?}",
+            valeurs
+        );
+        DesScriptes { valeurs, position: 0 }
+    }
+}
+
+impl SourceDes for DesScriptes {
+    fn lancer_de(&mut self) -> u8 {
+        let valeur = *self
+            .valeurs
+            .get(self.position)
+            .expect("La suite de dés scriptés est épuisée");
+        self.position += 1;
+        valeur
+    }
+}
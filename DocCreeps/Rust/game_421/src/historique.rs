@@ -0,0 +1,195 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+
+use crate::partie::Score;
+use crate::regles::Regles;
+
+/// Fichier d'historique utilisé par défaut, dans le répertoire courant.
+pub const FICHIER_HISTORIQUE: &str = "scores_421.json";
+
+/// Secondes écoulées depuis l'époque Unix.
+pub fn maintenant() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |duree| duree.as_secs())
+}
+
+/// Date UTC lisible : `"2024-03-01 18:05"`.
+pub fn formater_date(secondes: u64) -> String {
+    let jours = (secondes / 86_400) as i64;
+    let (heures, minutes) = (secondes % 86_400 / 3600, secondes % 3600 / 60);
+
+    // Conversion jours -> date du calendrier grégorien (algorithme de H. Hinnant).
+    let z = jours + 719_468;
+    let ere = z.div_euclid(146_097);
+    let jour_ere = z.rem_euclid(146_097);
+    let annee_ere = (jour_ere - jour_ere / 1460 + jour_ere / 36_524 - jour_ere / 146_096) / 365;
+    let jour_annee = jour_ere - (365 * annee_ere + annee_ere / 4 - annee_ere / 100);
+    let mp = (5 * jour_annee + 2) / 153;
+    let jour = jour_annee - (153 * mp + 2) / 5 + 1;
+    let mois = if mp < 10 { mp + 3 } else { mp - 9 };
+    let annee = annee_ere + ere * 400 + i64::from(mois <= 2);
+
+    format!("{:04}-{:02}-{:02} {:02}:{:02}", annee, mois, jour, heures, minutes)
+}
+
+/// Une session de jeu solo : les parties enchaînées avant de quitter.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Session {
+    pub graine: u64,
+    pub scores: Vec<Score>,
+    /// Jetons d'une combinaison ordinaire dans la table de règles de la
+    /// session : une partie qui rapporte davantage est gagnée. Les historiques
+    /// enregistrés sans ce champ ont été joués avec la table traditionnelle.
+    #[serde(default = "points_ordinaires_traditionnels")]
+    pub points_ordinaires: u32,
+}
+
+impl Session {
+    /// La partie rapporte plus qu'une combinaison ordinaire de la session.
+    pub fn est_gagnee(&self, score: &Score) -> bool {
+        score.points > self.points_ordinaires
+    }
+}
+
+fn points_ordinaires_traditionnels() -> u32 {
+    Regles::traditionnelles().points_ordinaires()
+}
+
+/// Historique des sessions, par nom de joueur, enregistré en JSON.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Historique {
+    pub joueurs: BTreeMap<String, Vec<Session>>,
+}
+
+impl Historique {
+    /// Charge l'historique ; un fichier absent donne un historique vide.
+    pub fn charger(chemin: &Path) -> Result<Self, String> {
+        let texte = match fs::read_to_string(chemin) {
+            Ok(texte) => texte,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Historique::default()),
+            Err(e) => return Err(format!("Impossible de lire '{}' : {}", chemin.display(), e)),
+        };
+        serde_json::from_str(&texte).map_err(|e| format!("Historique invalide '{}' : {}", chemin.display(), e))
+    }
+
+    /// Enregistre l'historique, en passant par un fichier temporaire pour ne
+    /// jamais laisser un fichier à moitié écrit.
+    pub fn sauvegarder(&self, chemin: &Path) -> Result<(), String> {
+        let erreur = |e: io::Error| format!("Impossible d'écrire '{}' : {}", chemin.display(), e);
+        let texte = serde_json::to_string_pretty(self).map_err(|e| e.to_string())?;
+        let temporaire = chemin.with_extension("json.tmp");
+        fs::write(&temporaire, texte).map_err(erreur)?;
+        fs::rename(&temporaire, chemin).map_err(erreur)
+    }
+
+    /// Ajoute une session au joueur ; une session sans partie est ignorée.
+    pub fn ajouter(&mut self, joueur: &str, session: Session) {
+        if !session.scores.is_empty() {
+            self.joueurs.entry(joueur.to_string()).or_default().push(session);
+        }
+    }
+
+    /// Bilan de chaque joueur, du meilleur score au moins bon (puis par moyenne).
+    pub fn classement(&self) -> Vec<Classement> {
+        let mut classement: Vec<Classement> = self
+            .joueurs
+            .iter()
+            .filter_map(|(joueur, sessions)| Classement::calculer(joueur, sessions))
+            .collect();
+        classement.sort_by(|a, b| {
+            b.meilleur
+                .points
+                .cmp(&a.meilleur.points)
+                .then(b.moyenne.total_cmp(&a.moyenne))
+                .then(a.joueur.cmp(&b.joueur))
+        });
+        classement
+    }
+}
+
+/// Bilan de toutes les parties d'un joueur.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Classement {
+    pub joueur: String,
+    pub parties: usize,
+    /// Meilleure partie ; à points égaux, la plus ancienne.
+    pub meilleur: Score,
+    pub moyenne: f64,
+    /// Part des parties terminées sur un 421.
+    pub frequence_421: f64,
+    /// Plus longue suite de parties consécutives terminées sur un 421.
+    pub serie_421: usize,
+    /// Plus longue suite de parties consécutives gagnées (voir
+    /// [`Session::est_gagnee`]), selon la table de règles de chaque session.
+    pub serie_gagnante: usize,
+}
+
+impl Classement {
+    fn calculer(joueur: &str, sessions: &[Session]) -> Option<Self> {
+        let scores: Vec<&Score> = sessions.iter().flat_map(|s| &s.scores).collect();
+        let meilleur = scores.iter().copied().reduce(|a, b| if b.points > a.points { b } else { a })?;
+
+        let gagnees: Vec<bool> = sessions
+            .iter()
+            .flat_map(|session| session.scores.iter().map(|score| session.est_gagnee(score)))
+            .collect();
+
+        let serie = |conditions: &mut dyn Iterator<Item = bool>| {
+            let (mut plus_longue, mut courante) = (0, 0);
+            for condition in conditions {
+                courante = if condition { courante + 1 } else { 0 };
+                plus_longue = plus_longue.max(courante);
+            }
+            plus_longue
+        };
+        let parties = scores.len();
+        Some(Classement {
+            joueur: joueur.to_string(),
+            parties,
+            meilleur: meilleur.clone(),
+            moyenne: scores.iter().map(|s| s.points as f64).sum::<f64>() / parties as f64,
+            frequence_421: scores.iter().filter(|s| s.est_421()).count() as f64 / parties as f64,
+            serie_421: serie(&mut scores.iter().map(|s| s.est_421())),
+            serie_gagnante: serie(&mut gagnees.into_iter()),
+        })
+    }
+}
+
+/// Tableau du classement de tous les joueurs.
+pub fn formater_classement(classement: &[Classement]) -> String {
+    if classement.is_empty() {
+        return "Aucune partie enregistrée.\n".to_string();
+    }
+    let separateur = "+----+----------------------+---------+------------------------------------+---------+--------+----------------------+\n";
+    let mut texte = String::new();
+    texte.push_str(separateur);
+    texte.push_str("| #  | Joueur               | Parties | Meilleur score                     | Moyenne | 421    | Séries 421 / gagnées |\n");
+    texte.push_str(separateur);
+    for (rang, ligne) in classement.iter().enumerate() {
+        let meilleur = format!(
+            "{} pts ({}, lancer {}) {}",
+            ligne.meilleur.points,
+            ligne.meilleur.combinaison,
+            ligne.meilleur.lancer_record,
+            formater_date(ligne.meilleur.fin).split(' ').next().unwrap_or_default()
+        );
+        texte.push_str(&format!(
+            "| {: <2} | {: <20} | {: <7} | {: <34} | {: <7.2} | {: <6} | {: <20} |\n",
+            rang + 1,
+            ligne.joueur,
+            ligne.parties,
+            meilleur,
+            ligne.moyenne,
+            format!("{:.1}%", ligne.frequence_421 * 100.0),
+            format!("{} / {}", ligne.serie_421, ligne.serie_gagnante)
+        ));
+    }
+    texte.push_str(separateur);
+    texte
+}
@@ -0,0 +1,282 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+use std::sync::Arc;
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+use crate::partie::{choisir_gardes, est_421, Partie};
+use crate::regles::{trier_des, Force, Regles};
+
+/// Choix recommandé pour la suite d'une partie.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Conseil {
+    /// Dés à garder avant de relancer, ou `None` pour s'arrêter.
+    pub gardes: Option<[bool; 3]>,
+    /// Points espérés en suivant ce conseil puis en jouant au mieux.
+    pub esperance: f64,
+}
+
+/// Combinaison qui compte à la fin d'un tour.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Comptage {
+    /// La meilleure combinaison obtenue, comme dans une [`Partie`] solo :
+    /// relancer ne fait jamais perdre ce qui est acquis.
+    Meilleure,
+    /// La dernière combinaison, comme pour un tour à la [`Table`](crate::table::Table).
+    Derniere,
+}
+
+/// Stratégie optimale par calcul d'espérance.
+///
+/// Le gain est la valeur (selon la table de règles) de la combinaison qui
+/// compte à la fin du tour, selon le [`Comptage`]. Pour chaque état (dés,
+/// meilleure combinaison, lancers restants), le solveur compare l'arrêt à
+/// chacun des choix de dés gardés, en énumérant tous les résultats possibles
+/// de la relance. Les valeurs sont mémorisées.
+///
+/// ```
+/// use std::sync::Arc;
+/// use game_421::ia::{Comptage, Solveur};
+/// use game_421::regles::Regles;
+///
+/// let mut solveur = Solveur::new(Arc::new(Regles::traditionnelles()), Comptage::Derniere);
+/// // Avec 4 et 2 en main, on garde les deux et on relance le 6.
+/// let conseil = solveur.conseil(&[4, 6, 2], &[4, 6, 2], 1);
+/// assert_eq!(conseil.gardes, Some([true, false, true]));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Solveur {
+    comptage: Comptage,
+    /// Points et force de chacun des 216 lancers, indexés par [`indice`] :
+    /// la table de règles n'est consultée qu'une fois.
+    points: Vec<u32>,
+    forces: Vec<Force>,
+    memo: HashMap<Position, f64>,
+    /// Meilleur choix par position, sur les dés triés.
+    conseils: HashMap<Position, Conseil>,
+}
+
+/// Position du solveur : dés triés, meilleure combinaison triée, lancers restants.
+type Position = ([u8; 3], [u8; 3], u32);
+
+impl Solveur {
+    pub fn new(regles: Arc<Regles>, comptage: Comptage) -> Self {
+        let lancers: Vec<[u8; 3]> = (0..216u8).map(|i| [i / 36 + 1, i / 6 % 6 + 1, i % 6 + 1]).collect();
+        Solveur {
+            comptage,
+            points: lancers.iter().map(|des| regles.points(des)).collect(),
+            forces: lancers.iter().map(|des| regles.force(des)).collect(),
+            memo: HashMap::new(),
+            conseils: HashMap::new(),
+        }
+    }
+
+    pub fn comptage(&self) -> Comptage {
+        self.comptage
+    }
+
+    /// Espérance de points en jouant au mieux depuis `des`, avec `restants`
+    /// relances et `meilleur` pour meilleure combinaison obtenue jusqu'ici
+    /// (sans effet en [`Comptage::Derniere`]).
+    pub fn valeur(&mut self, des: &[u8; 3], meilleur: &[u8; 3], restants: u32) -> f64 {
+        let position = self.position(des, meilleur, restants);
+        let points = self.points[indice(&self.acquis(&position))] as f64;
+        if restants == 0 || est_421(des) {
+            return points;
+        }
+        if let Some(&valeur) = self.memo.get(&position) {
+            return valeur;
+        }
+        let valeur = (0..7)
+            .map(|masque| self.esperance_relance(&position, masque_gardes(masque)))
+            .fold(points, f64::max);
+        self.memo.insert(position, valeur);
+        valeur
+    }
+
+    /// Meilleur choix depuis `des` avec `restants` relances : s'arrêter ou
+    /// garder certains dés. À espérance égale, s'arrêter est préféré, puis
+    /// garder le plus de dés.
+    pub fn conseil(&mut self, des: &[u8; 3], meilleur: &[u8; 3], restants: u32) -> Conseil {
+        let position = self.position(des, meilleur, restants);
+        let conseil = match self.conseils.get(&position) {
+            Some(&conseil) => conseil,
+            None => {
+                let conseil = self.calculer_conseil(&position);
+                self.conseils.insert(position, conseil);
+                conseil
+            }
+        };
+        // Reporte sur les dés dans leur ordre d'origine les valeurs gardées.
+        let gardes = conseil.gardes.map(|gardes| {
+            let valeurs: Vec<u8> = (0..3).filter(|&i| gardes[i]).map(|i| position.0[i]).collect();
+            choisir_gardes(des, &valeurs).expect("valeurs prises parmi les dés")
+        });
+        Conseil { gardes, ..conseil }
+    }
+
+    /// Conseil pour la partie en cours, qui doit attendre une décision.
+    pub fn conseil_partie(&mut self, partie: &Partie) -> Conseil {
+        let meilleur = partie.meilleur().map_or(partie.des(), |m| m.des);
+        self.conseil(&partie.des(), &meilleur, partie.lancers_restants())
+    }
+
+    /// Position normalisée ; en [`Comptage::Derniere`], seuls les dés comptent.
+    fn position(&self, des: &[u8; 3], meilleur: &[u8; 3], restants: u32) -> Position {
+        let des = trier_des(des);
+        match self.comptage {
+            Comptage::Derniere => (des, des, restants),
+            Comptage::Meilleure => (des, self.plus_forte(des, trier_des(meilleur)), restants),
+        }
+    }
+
+    /// Combinaison qui compte si le joueur s'arrête dans cet état.
+    fn acquis(&self, position: &Position) -> [u8; 3] {
+        match self.comptage {
+            Comptage::Derniere => position.0,
+            Comptage::Meilleure => position.1,
+        }
+    }
+
+    /// À force égale, la combinaison déjà acquise est conservée, comme dans [`Partie`].
+    fn plus_forte(&self, des: [u8; 3], meilleur: [u8; 3]) -> [u8; 3] {
+        if self.forces[indice(&des)] > self.forces[indice(&meilleur)] {
+            des
+        } else {
+            meilleur
+        }
+    }
+
+    fn calculer_conseil(&mut self, position: &Position) -> Conseil {
+        let mut conseil = Conseil {
+            gardes: None,
+            esperance: self.points[indice(&self.acquis(position))] as f64,
+        };
+        if position.2 == 0 || est_421(&position.0) {
+            return conseil;
+        }
+        // Masques du plus grand nombre de dés gardés au plus petit.
+        let mut masques: Vec<u8> = (0..7).collect();
+        masques.sort_by_key(|m| std::cmp::Reverse(m.count_ones()));
+        for masque in masques {
+            let gardes = masque_gardes(masque);
+            let esperance = self.esperance_relance(position, gardes);
+            if esperance > conseil.esperance + 1e-9 {
+                conseil = Conseil { gardes: Some(gardes), esperance };
+            }
+        }
+        conseil
+    }
+
+    /// Espérance en relançant les dés non gardés, puis en jouant au mieux.
+    fn esperance_relance(&mut self, position: &Position, gardes: [bool; 3]) -> f64 {
+        let (des, meilleur, restants) = *position;
+        let libres: Vec<usize> = (0..3).filter(|&i| !gardes[i]).collect();
+        let issues = 6u32.pow(libres.len() as u32);
+        let mut total = 0.0;
+        for issue in 0..issues {
+            let mut nouveaux = des;
+            let mut reste = issue;
+            for &i in &libres {
+                nouveaux[i] = (reste % 6) as u8 + 1;
+                reste /= 6;
+            }
+            let meilleur = self.plus_forte(trier_des(&nouveaux), meilleur);
+            total += self.valeur(&nouveaux, &meilleur, restants - 1);
+        }
+        total / issues as f64
+    }
+}
+
+fn indice(des: &[u8; 3]) -> usize {
+    (des[0] as usize - 1) * 36 + (des[1] as usize - 1) * 6 + (des[2] as usize - 1)
+}
+
+fn masque_gardes(masque: u8) -> [bool; 3] {
+    [masque & 1 != 0, masque & 2 != 0, masque & 4 != 0]
+}
+
+/// Niveau de difficulté d'un joueur ordinateur.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Niveau {
+    /// S'arrête ou garde des dés au hasard.
+    Aleatoire,
+    /// Ne regarde que le prochain lancer.
+    Glouton,
+    /// Joue la stratégie optimale du [`Solveur`].
+    Esperance,
+}
+
+impl Niveau {
+    pub const TOUS: [Niveau; 3] = [Niveau::Aleatoire, Niveau::Glouton, Niveau::Esperance];
+
+    pub fn nom(&self) -> &'static str {
+        match self {
+            Niveau::Aleatoire => "aleatoire",
+            Niveau::Glouton => "glouton",
+            Niveau::Esperance => "esperance",
+        }
+    }
+}
+
+impl fmt::Display for Niveau {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.nom())
+    }
+}
+
+impl FromStr for Niveau {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Niveau::TOUS
+            .into_iter()
+            .find(|n| n.nom() == s)
+            .ok_or_else(|| format!("Niveau inconnu : '{}' (aleatoire, glouton, esperance)", s))
+    }
+}
+
+/// Joueur ordinateur : décide, à chaque étape d'une partie, de s'arrêter ou
+/// des dés à garder, en visant la combinaison qui compte selon le [`Comptage`].
+#[derive(Debug, Clone)]
+pub struct Ordinateur {
+    niveau: Niveau,
+    solveur: Solveur,
+    rng: StdRng,
+}
+
+impl Ordinateur {
+    /// La graine ne sert qu'au niveau aléatoire.
+    pub fn new(niveau: Niveau, regles: Arc<Regles>, comptage: Comptage, graine: u64) -> Self {
+        Ordinateur {
+            niveau,
+            solveur: Solveur::new(regles, comptage),
+            rng: StdRng::seed_from_u64(graine),
+        }
+    }
+
+    pub fn niveau(&self) -> Niveau {
+        self.niveau
+    }
+
+    /// Dés à garder pour relancer, ou `None` pour s'arrêter. À n'appeler que
+    /// lorsque la partie attend une décision.
+    pub fn decider(&mut self, partie: &Partie) -> Option<[bool; 3]> {
+        let des = partie.des();
+        let meilleur = partie.meilleur().map_or(des, |m| m.des);
+        let restants = partie.lancers_restants();
+        match self.niveau {
+            Niveau::Aleatoire => {
+                if self.rng.gen_bool(0.5) {
+                    None
+                } else {
+                    Some(masque_gardes(self.rng.gen_range(0..7)))
+                }
+            }
+            Niveau::Glouton => self.solveur.conseil(&des, &meilleur, restants.min(1)).gardes,
+            Niveau::Esperance => self.solveur.conseil_partie(partie).gardes,
+        }
+    }
+}
@@ -0,0 +1,159 @@
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+
+use crate::des::{DesAleatoires, SourceDes};
+use crate::partie::{formater_des, Etat, Evenement, Partie, Score};
+use crate::regles::Regles;
+
+/// Une étape d'une partie, dans l'ordre où elle a été jouée.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Coup {
+    /// Dés obtenus à un lancer et leur valeur selon la table de règles.
+    Lancer { des: [u8; 3], points: u32 },
+    /// Dés gardés avant le lancer suivant.
+    Garder { gardes: [bool; 3] },
+    /// Le joueur s'arrête sur sa combinaison.
+    Arreter,
+}
+
+/// Coups d'une partie de la session.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PartieJournal {
+    pub max_lancers: u32,
+    pub coups: Vec<Coup>,
+}
+
+/// Journal d'une session solo, enregistré en JSON : la graine, la table de
+/// règles, chaque lancer et chaque décision, puis le tableau des scores.
+///
+/// [`Journal::rejouer`] rejoue les décisions avec des dés tirés de la même
+/// graine et vérifie que chaque lancer, ses points et les scores sont
+/// identiques : un résultat contesté se revérifie, et un journal sert de test
+/// de non-régression pour le calcul des points.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Journal {
+    pub graine: u64,
+    /// Table de règles, dans le format de [`Regles::from_str`](std::str::FromStr).
+    pub regles: String,
+    pub parties: Vec<PartieJournal>,
+    pub scores: Vec<Score>,
+}
+
+impl Journal {
+    pub fn new(graine: u64, regles: &Regles) -> Self {
+        Journal {
+            graine,
+            regles: regles.to_string(),
+            parties: Vec::new(),
+            scores: Vec::new(),
+        }
+    }
+
+    /// Charge un journal enregistré par [`Journal::sauvegarder`].
+    pub fn charger(chemin: &Path) -> Result<Self, String> {
+        let texte = fs::read_to_string(chemin)
+            .map_err(|e| format!("Impossible de lire '{}' : {}", chemin.display(), e))?;
+        serde_json::from_str(&texte).map_err(|e| format!("Journal invalide '{}' : {}", chemin.display(), e))
+    }
+
+    pub fn sauvegarder(&self, chemin: &Path) -> Result<(), String> {
+        let erreur = |e: io::Error| format!("Impossible d'écrire '{}' : {}", chemin.display(), e);
+        let texte = serde_json::to_string_pretty(self).map_err(|e| e.to_string())?;
+        fs::write(chemin, texte).map_err(erreur)
+    }
+
+    /// Commence une nouvelle partie ; les coups suivants lui sont ajoutés.
+    pub fn nouvelle_partie(&mut self, max_lancers: u32) {
+        self.parties.push(PartieJournal { max_lancers, coups: Vec::new() });
+    }
+
+    pub fn noter(&mut self, coup: Coup) {
+        self.parties
+            .last_mut()
+            .expect("nouvelle_partie appelée avant le premier coup")
+            .coups
+            .push(coup);
+    }
+
+    /// Note les lancers parmi les événements d'une action.
+    pub fn noter_evenements(&mut self, evenements: &[Evenement]) {
+        for evenement in evenements {
+            if let Evenement::DesLances { des, points, .. } = *evenement {
+                self.noter(Coup::Lancer { des, points });
+            }
+        }
+    }
+
+    /// Score d'une partie terminée, pour le tableau final.
+    pub fn terminer_partie(&mut self, score: Score) {
+        self.scores.push(score);
+    }
+
+    /// Rejoue la session avec des dés tirés de la graine, et renvoie le
+    /// tableau des scores obtenu. Échoue au premier écart avec le journal.
+    pub fn rejouer(&self) -> Result<Vec<Score>, String> {
+        self.rejouer_avec(&mut DesAleatoires::new(self.graine))
+    }
+
+    /// Comme [`Journal::rejouer`], avec une autre source de dés.
+    pub fn rejouer_avec(&self, source: &mut dyn SourceDes) -> Result<Vec<Score>, String> {
+        let regles: Regles = self.regles.parse().map_err(|e| format!("Table de règles du journal : {}", e))?;
+        let regles = Arc::new(regles);
+        if self.scores.len() != self.parties.len() {
+            return Err(format!(
+                "{} partie(s) jouée(s) pour {} score(s) dans le journal",
+                self.parties.len(),
+                self.scores.len()
+            ));
+        }
+
+        let mut scores = Vec::new();
+        for (i, (journal, attendu)) in self.parties.iter().zip(&self.scores).enumerate() {
+            let numero = i as u32 + 1;
+            let erreur = |message: String| format!("Partie {} : {}", numero, message);
+            let mut partie = Partie::avec_regles(journal.max_lancers, Arc::clone(&regles)).map_err(erreur)?;
+
+            for (j, coup) in journal.coups.iter().enumerate() {
+                let erreur = |message: String| erreur(format!("coup {} : {}", j + 1, message));
+                match *coup {
+                    Coup::Lancer { des, points } => {
+                        partie.lancer(source).map_err(erreur)?;
+                        let (obtenus, valeur) = (partie.des(), regles.points(&partie.des()));
+                        if (obtenus, valeur) != (des, points) {
+                            return Err(erreur(format!(
+                                "lancer {} ({} points) au lieu de {} ({} points)",
+                                formater_des(&obtenus),
+                                valeur,
+                                formater_des(&des),
+                                points
+                            )));
+                        }
+                    }
+                    Coup::Garder { gardes } => partie.garder(gardes).map_err(erreur)?,
+                    Coup::Arreter => {
+                        partie.arreter().map_err(erreur)?;
+                    }
+                }
+            }
+
+            let resultat = match (partie.etat(), partie.resultat()) {
+                (Etat::Terminee, Some(resultat)) => resultat,
+                _ => return Err(erreur("partie inachevée dans le journal".to_string())),
+            };
+            // Les horaires ne se rejouent pas : ils sont repris du journal.
+            let score = Score::new(numero, &resultat, attendu.debut, attendu.fin);
+            if score != *attendu {
+                return Err(erreur(format!(
+                    "score de {} points avec {} au lieu de {} points avec {}",
+                    score.points, score.combinaison, attendu.points, attendu.combinaison
+                )));
+            }
+            scores.push(score);
+        }
+        Ok(scores)
+    }
+}
@@ -0,0 +1,31 @@
+//! Moteur du jeu du 421, indépendant de la console.
+//!
+//! Le hasard est injecté via [`des::SourceDes`] : une partie jouée avec
+//! [`des::DesAleatoires`] et une graine donnée se rejoue à l'identique.
+//! [`partie::Partie`] est une machine à états sans entrée/sortie : elle reçoit
+//! des actions (lancer, s'arrêter) et renvoie des [`partie::Evenement`] que
+//! l'interface se charge d'afficher. Le classement et la valeur des
+//! combinaisons viennent d'une table [`regles::Regles`], modifiable sans
+//! recompiler. [`table::Table`] enchaîne les tours de plusieurs joueurs
+//! (charge puis décharge) avec ces mêmes parties ; [`ia`] fournit des
+//! joueurs ordinateur et un solveur qui conseille les dés à garder, et
+//! [`simulation`] les fait jouer en masse pour estimer les probabilités.
+//! Les scores des sessions solo sont conservés par [`historique`], et
+//! [`reseau`] héberge une table dont les joueurs se connectent en TCP. Une
+//! session solo s'enregistre coup par coup dans un [`journal::Journal`], qui
+//! se rejoue à l'identique à partir de sa graine.
+
+pub mod des;
+pub mod historique;
+pub mod ia;
+pub mod journal;
+pub mod partie;
+pub mod regles;
+pub mod reseau;
+pub mod simulation;
+pub mod table;
+
+// Déclaration du module de tests
+#[cfg(test)]
+#[path = "tests/mod.rs"]
+mod tests;
@@ -1,170 +1,484 @@
-use std::io;
-use std::io::Write;
-use rand::Rng;
-
-struct Score {
-    partie: u32,
-    points: u32,
-    combinaison: String,
-    lancers: u32,
+use std::fs::File;
+use std::io::{self, BufReader, BufWriter, Write};
+use std::net::{IpAddr, SocketAddr, TcpListener, TcpStream};
+use std::ops::RangeInclusive;
+use std::path::{Path, PathBuf};
+use std::process;
+use std::sync::Arc;
+
+use game_421::des::DesAleatoires;
+use game_421::historique::{formater_classement, Historique, Session, FICHIER_HISTORIQUE};
+use game_421::ia::Niveau;
+use game_421::journal::Journal;
+use game_421::partie::MAX_LANCERS;
+use game_421::regles::Regles;
+use game_421::reseau::{self, Serveur};
+use game_421::simulation::{self, ConfigSimulation};
+use game_421::table::{MAX_JOUEURS, MIN_JOUEURS};
+
+mod console;
+mod tui;
+
+use console::Console;
+
+const USAGE: &str = concat!(
+    "Utilisation : game_421 [--seed <n>] [--regles <fichier>] [--nom <joueur>] [--historique <fichier>]\n",
+    "                       [--joueurs <n>] [--ia <niveau>]... [--tui] [--journal <fichier>]\n",
+    "              game_421 classement [--historique <fichier>]\n",
+    "              game_421 rejouer <journal>\n",
+    "              game_421 serveur [--port <n>] [--joueurs <n>] [--lancers <n>] [--seed <n>] [--regles <fichier>]\n",
+    "              game_421 rejoindre <adresse>[:port] [--nom <joueur>]\n",
+    "              game_421 simuler [--parties <n>] [--lancers <a-b>] [--strategie <niveau>]\n",
+    "                               [--seed <n>] [--regles <fichier>] [--csv <fichier>]\n",
+    "  --nom <joueur>   nom sous lequel la session solo est enregistrée\n",
+    "  --historique     fichier des scores (défaut : scores_421.json)\n",
+    "  --joueurs <n>    nombre de joueurs humains (partie à plusieurs)\n",
+    "  --ia <niveau>    ajoute un joueur ordinateur : aleatoire, glouton ou esperance\n",
+    "  --tui            partie solo en plein écran (dés dessinés, touches 1 à 3 pour garder)\n",
+    "  --journal        enregistre chaque lancer et chaque décision de la session solo\n",
+    "  classement       affiche le classement de tous les joueurs enregistrés\n",
+    "  rejouer          rejoue un journal et vérifie qu'il redonne les mêmes scores\n",
+    "  serveur          héberge une table en réseau (défauts : port 4210, 2 joueurs,\n",
+    "                   3 lancers) ; le serveur lance tous les dés\n",
+    "  rejoindre        rejoint une table hébergée par « game_421 serveur »\n",
+    "  simuler          joue des parties sans interface (défauts : 1000000 parties,\n",
+    "                   3 lancers, stratégie esperance) et affiche les probabilités",
+);
+
+/// Options de la ligne de commande.
+#[derive(Debug, Default, PartialEq)]
+struct Options {
+    graine: Option<u64>,
+    regles: Option<PathBuf>,
+    /// Nombre de joueurs humains ; `None` pour une partie solo.
+    joueurs: Option<usize>,
+    /// Niveaux des joueurs ordinateur.
+    ordinateurs: Vec<Niveau>,
+    /// Nom du joueur solo dans l'historique.
+    nom: Option<String>,
+    historique: Option<PathBuf>,
+    /// Fichier où enregistrer le journal de la session solo.
+    journal: Option<PathBuf>,
+    /// Interface plein écran au lieu de la console.
+    tui: bool,
 }
 
-fn main() {
-    println!("Bienvenue dans le jeu du 421 !");
-
-    let max_lancers = demander_nombre_lancers();
-    println!("Vous avez choisi {} lancer(s) par partie.", max_lancers);
-
-    let mut scores: Vec<Score> = Vec::new();
-    let mut score_total = 0;
-    let mut numero_partie = 1;
-    let mut total_lancers = 0;
-
-    loop {
-        println!("\nPartie {} :", numero_partie);
-        let (points, combinaison, lancers) = jouer_partie(max_lancers);  // Modifiez cette ligne
-        score_total += points;
-        total_lancers += lancers;
-
-        scores.push(Score {
-            partie: numero_partie,
-            points,
-            combinaison,
-            lancers,
-        });
+/// Options de la commande `simuler`.
+#[derive(Debug, PartialEq)]
+struct OptionsSimulation {
+    parties: u64,
+    lancers: RangeInclusive<u32>,
+    strategie: Niveau,
+    graine: Option<u64>,
+    regles: Option<PathBuf>,
+    csv: Option<PathBuf>,
+}
 
-        println!("Points de cette partie : {}", points);
-        println!("Score total : {}", score_total);
+/// Options de la commande `serveur`.
+#[derive(Debug, PartialEq)]
+struct OptionsServeur {
+    port: u16,
+    joueurs: usize,
+    max_lancers: u32,
+    graine: Option<u64>,
+    regles: Option<PathBuf>,
+}
 
-        if !demander_rejouer() {
-            break;
+impl Default for OptionsServeur {
+    fn default() -> Self {
+        OptionsServeur {
+            port: reseau::PORT,
+            joueurs: MIN_JOUEURS,
+            max_lancers: 3,
+            graine: None,
+            regles: None,
         }
-
-        numero_partie += 1;
     }
-
-    afficher_tableau_scores(&scores, score_total, total_lancers);
-    println!("Merci d'avoir joué !");
 }
-fn demander_nombre_lancers() -> u32 {
-    loop {
-        print!("Combien de lancers voulez-vous par partie ? (1-15) : ");
-        io::stdout().flush().unwrap();
-
-        let mut input = String::new();
-        io::stdin().read_line(&mut input).unwrap();
 
-        match input.trim().parse() {
-            Ok(n) if n >= 1 && n <= 15 => return n,
-            _ => println!("Veuillez entrer un nombre entre 1 et 15."),
+impl Default for OptionsSimulation {
+    fn default() -> Self {
+        OptionsSimulation {
+            parties: 1_000_000,
+            lancers: 3..=3,
+            strategie: Niveau::Esperance,
+            graine: None,
+            regles: None,
+            csv: None,
         }
     }
 }
-fn jouer_partie(max_lancers: u32) -> (u32, String, u32) {
-    let mut des = [0, 0, 0];
-    let mut meilleur_score = 0;
-    let mut meilleure_combinaison = String::new();
-    let mut lancers = 0;
-
-    while lancers < max_lancers {
-        lancers += 1;
-        lancer_des(&mut des);
-        afficher_des(&des);
 
-        let points = calculer_points(&des);
-        let combinaison = format!("{} {} {}", des[0], des[1], des[2]);
-
-        println!("Lancer {} : {} points", lancers, points);
-
-        if points > meilleur_score {
-            meilleur_score = points;
-            meilleure_combinaison = combinaison.clone();
+fn main() {
+    let mut args = std::env::args().skip(1).peekable();
+    if args.peek().map(String::as_str) == Some("simuler") {
+        args.next();
+        let options = lire_options_simulation(args).unwrap_or_else(|message| erreur_usage(&message));
+        if let Err(message) = lancer_simulation(options) {
+            eprintln!("Erreur : {}", message);
+            process::exit(1);
         }
+        return;
+    }
+    if args.peek().map(String::as_str) == Some("classement") {
+        args.next();
+        let chemin = lire_option_historique(args).unwrap_or_else(|message| erreur_usage(&message));
+        match Historique::charger(&chemin) {
+            Ok(historique) => print!("{}", formater_classement(&historique.classement())),
+            Err(message) => {
+                eprintln!("Erreur : {}", message);
+                process::exit(1);
+            }
+        }
+        return;
+    }
 
-        if points == 10 {  // Si on obtient 421, on arrête immédiatement
-            println!("Bravo ! Vous avez obtenu 421 !");
-            return (points, combinaison, lancers);
+    if args.peek().map(String::as_str) == Some("rejouer") {
+        args.next();
+        let chemin = match (args.next(), args.next()) {
+            (Some(chemin), None) => PathBuf::from(chemin),
+            _ => erreur_usage("rejouer attend un seul fichier journal"),
+        };
+        if let Err(message) = rejouer(&chemin) {
+            eprintln!("Erreur : {}", message);
+            process::exit(1);
+        }
+        return;
+    }
+    if args.peek().map(String::as_str) == Some("serveur") {
+        args.next();
+        let options = lire_options_serveur(args).unwrap_or_else(|message| erreur_usage(&message));
+        if let Err(erreur) = heberger(options) {
+            eprintln!("Erreur : {}", erreur);
+            process::exit(1);
+        }
+        return;
+    }
+    if args.peek().map(String::as_str) == Some("rejoindre") {
+        args.next();
+        let (adresse, nom) = lire_options_client(args).unwrap_or_else(|message| erreur_usage(&message));
+        let resultat = TcpStream::connect(&adresse).and_then(|flux| {
+            let reception = BufReader::new(flux.try_clone()?);
+            Console::new(io::stdin().lock(), io::stdout()).session_reseau(reception, flux, &nom)
+        });
+        if let Err(erreur) = resultat {
+            eprintln!("\nErreur ({}) : {}", adresse, erreur);
+            process::exit(1);
         }
+        return;
+    }
 
-        if lancers < max_lancers && !demander_relancer(lancers, max_lancers) {
-            break;
+    let options = lire_options(args).unwrap_or_else(|message| erreur_usage(&message));
+    let regles = charger_regles(options.regles.as_deref());
+    let points_ordinaires = regles.points_ordinaires();
+    let graine = options.graine.unwrap_or_else(rand::random);
+    let mut source = DesAleatoires::new(graine);
+
+    if options.tui {
+        match tui::run(&mut source, regles, graine) {
+            Ok(journal) => enregistrer_session(&options, journal, points_ordinaires),
+            Err(erreur) => {
+                eprintln!("Erreur : {}", erreur);
+                process::exit(1);
+            }
         }
+        return;
     }
 
-    println!("Votre meilleur score : {} points avec la combinaison {}", meilleur_score, meilleure_combinaison);
-    (meilleur_score, meilleure_combinaison, lancers)
+    let mut console = Console::new(io::stdin().lock(), io::stdout()).avec_regles(regles);
+    let resultat = match (options.joueurs, options.ordinateurs.is_empty()) {
+        (None, true) => console.session(&mut source, graine).map(Some),
+        (humains, _) => console
+            .session_table(&mut source, graine, humains.unwrap_or(1), &options.ordinateurs)
+            .map(|()| None),
+    };
+    match resultat {
+        Ok(Some(journal)) => enregistrer_session(&options, journal, points_ordinaires),
+        Ok(None) => {}
+        Err(erreur) => {
+            eprintln!("\nErreur : {}", erreur);
+            process::exit(1);
+        }
+    }
 }
 
-fn lancer_des(des: &mut [u8; 3]) {
-    let mut rng = rand::thread_rng();
-    for de in des.iter_mut() {
-        *de = rng.gen_range(1..=6);
+/// Enregistre le journal demandé par `--journal`, puis ajoute la session solo
+/// à l'historique, sous `--nom`, `$USER` ou « Joueur ».
+fn enregistrer_session(options: &Options, journal: Journal, points_ordinaires: u32) {
+    if let Some(chemin) = &options.journal {
+        match journal.sauvegarder(chemin) {
+            Ok(()) => println!("Journal de la session enregistré dans {}", chemin.display()),
+            Err(message) => eprintln!("Erreur : {}", message),
+        }
+    }
+    let Journal { graine, scores, .. } = journal;
+    let nom = options
+        .nom
+        .clone()
+        .or_else(|| std::env::var("USER").ok())
+        .unwrap_or_else(|| "Joueur".to_string());
+    let chemin = options.historique.clone().unwrap_or_else(|| PathBuf::from(FICHIER_HISTORIQUE));
+    let enregistrement = Historique::charger(&chemin).and_then(|mut historique| {
+        historique.ajouter(&nom, Session { graine, scores, points_ordinaires });
+        historique.sauvegarder(&chemin)
+    });
+    match enregistrement {
+        Ok(()) => println!("Scores de {} enregistrés dans {}", nom, chemin.display()),
+        Err(message) => eprintln!("Erreur : {}", message),
     }
 }
 
-fn afficher_des(des: &[u8; 3]) {
-    println!("Résultat du lancer : {} {} {}", des[0], des[1], des[2]);
+fn erreur_usage(message: &str) -> ! {
+    eprintln!("Erreur : {}", message);
+    eprintln!("{}", USAGE);
+    process::exit(2);
 }
 
-fn calculer_points(des: &[u8; 3]) -> u32 {
-    let mut sorted_des = des.to_vec();
-    sorted_des.sort_unstable();
-
-    match sorted_des[..] {
-        [1, 2, 4] => 10, // 421
-        [1, 1, 1] => 7,  // 111
-        [1, 1, 6] => 6,  // 611
-        [6, 6, 6] => 6,  // 666
-        [1, 1, 5] => 5,  // 511
-        [5, 5, 5] => 5,  // 555
-        _ => 1,          // Autres combinaisons
+/// Table de règles du fichier donné, ou la table traditionnelle.
+fn charger_regles(chemin: Option<&Path>) -> Regles {
+    match chemin {
+        Some(chemin) => Regles::charger(chemin).unwrap_or_else(|message| {
+            eprintln!("Erreur : {}", message);
+            process::exit(2);
+        }),
+        None => Regles::traditionnelles(),
     }
 }
 
-fn demander_relancer(lancers_actuels: u32, max_lancers: u32) -> bool {
-    print!("Voulez-vous relancer ? (o/n) [Il vous reste {} lancer(s)] : ", max_lancers - lancers_actuels);
-    io::stdout().flush().unwrap();
-
-    let mut reponse = String::new();
-    io::stdin().read_line(&mut reponse).unwrap();
-
-    reponse.trim().to_lowercase() == "o"
+/// Rejoue un journal et affiche les scores retrouvés.
+fn rejouer(chemin: &Path) -> Result<(), String> {
+    let journal = Journal::charger(chemin)?;
+    println!("Rejeu de {} (graine {}, {} partie(s))", chemin.display(), journal.graine, journal.parties.len());
+    let scores = journal.rejouer()?;
+    let mut console = Console::new(io::empty(), io::stdout());
+    console.afficher_tableau_scores(&scores, journal.graine).map_err(|e| e.to_string())?;
+    println!("\nJournal vérifié : chaque lancer et le tableau des scores sont identiques.");
+    Ok(())
 }
 
-fn demander_rejouer() -> bool {
-    print!("Voulez-vous jouer une autre partie ? (o/n) : ");
-    io::stdout().flush().unwrap();
+fn heberger(options: OptionsServeur) -> io::Result<()> {
+    let regles = Arc::new(charger_regles(options.regles.as_deref()));
+    let graine = options.graine.unwrap_or_else(rand::random);
+    let ecoute = TcpListener::bind(("0.0.0.0", options.port))?;
+    println!(
+        "Table ouverte sur le port {} : en attente de {} joueurs ({} lancer(s) au plus).",
+        ecoute.local_addr()?.port(),
+        options.joueurs,
+        options.max_lancers
+    );
+    println!("Graine : {}", graine);
+
+    let delai = reseau::DELAI_PRESENTATION;
+    let mut serveur = Serveur::accueillir(&ecoute, options.joueurs, options.max_lancers, regles, delai)?;
+    let noms: Vec<&str> = serveur.table().joueurs().iter().map(|j| j.nom.as_str()).collect();
+    println!("La partie commence : {}", noms.join(", "));
+    let perdant = serveur.jouer(&mut DesAleatoires::new(graine))?;
+    println!("{} a tous les jetons et perd la partie.", serveur.table().joueurs()[perdant].nom);
+    Ok(())
+}
 
-    let mut reponse = String::new();
-    io::stdin().read_line(&mut reponse).unwrap();
+fn lancer_simulation(options: OptionsSimulation) -> Result<(), String> {
+    let config = ConfigSimulation {
+        parties: options.parties,
+        lancers: options.lancers,
+        strategie: options.strategie,
+        graine: options.graine.unwrap_or_else(rand::random),
+        regles: Arc::new(charger_regles(options.regles.as_deref())),
+    };
+    println!(
+        "Simulation : {} parties par nombre de lancers ({}-{}), stratégie {}, graine {}",
+        config.parties,
+        config.lancers.start(),
+        config.lancers.end(),
+        config.strategie,
+        config.graine
+    );
+
+    let rapports = simulation::simuler(&config);
+    print!("{}", simulation::formater_resume(&rapports));
+    for rapport in &rapports {
+        println!();
+        print!("{}", simulation::histogramme(rapport, 40));
+    }
 
-    reponse.trim().to_lowercase() == "o"
+    if let Some(chemin) = options.csv {
+        let erreur = |e: io::Error| format!("Impossible d'écrire '{}' : {}", chemin.display(), e);
+        let mut fichier = BufWriter::new(File::create(&chemin).map_err(erreur)?);
+        simulation::ecrire_csv(&rapports, &mut fichier)
+            .and_then(|()| fichier.flush())
+            .map_err(erreur)?;
+        println!("\nRésultats écrits dans {}", chemin.display());
+    }
+    Ok(())
 }
 
-fn afficher_tableau_scores(scores: &[Score], score_total: u32, total_lancers: u32) {
-println!("\nTableau des scores :");
-println!("+---------+--------+-------------+----------+");
-println!("| Partie  | Points | Combinaison | Lancers  |");
-println!("+---------+--------+-------------+----------+");
+/// Lit l'option `--historique <fichier>` de la commande `classement`.
+fn lire_option_historique(args: impl Iterator<Item = String>) -> Result<PathBuf, String> {
+    let mut chemin = PathBuf::from(FICHIER_HISTORIQUE);
+    let mut args = args;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--historique" => chemin = PathBuf::from(args.next().ok_or("Valeur manquante pour --historique")?),
+            autre => return Err(format!("Option inconnue : '{}'", autre)),
+        }
+    }
+    Ok(chemin)
+}
 
-for score in scores {
-println!("| {: <7} | {: <6} | {: <11} | {: <8} |", score.partie, score.points, score.combinaison, score.lancers);
+/// Lit les options de la commande `serveur`.
+fn lire_options_serveur(args: impl Iterator<Item = String>) -> Result<OptionsServeur, String> {
+    let mut options = OptionsServeur::default();
+    let mut args = args;
+    while let Some(arg) = args.next() {
+        let valeur = args.next().ok_or(format!("Valeur manquante pour {}", arg))?;
+        let invalide = |quoi: &str| format!("{} invalide : '{}'", quoi, valeur);
+        match arg.as_str() {
+            "--port" => options.port = valeur.parse().map_err(|_| invalide("Port"))?,
+            "--joueurs" => {
+                options.joueurs = valeur
+                    .parse()
+                    .ok()
+                    .filter(|n| (MIN_JOUEURS..=MAX_JOUEURS).contains(n))
+                    .ok_or_else(|| format!("Il faut entre {} et {} joueurs ({} demandés)", MIN_JOUEURS, MAX_JOUEURS, valeur))?;
+            }
+            "--lancers" => {
+                options.max_lancers = valeur
+                    .parse()
+                    .ok()
+                    .filter(|n| (1..=MAX_LANCERS).contains(n))
+                    .ok_or_else(|| invalide("Nombre de lancers"))?;
+            }
+            "--seed" => options.graine = Some(valeur.parse().map_err(|_| invalide("Graine"))?),
+            "--regles" => options.regles = Some(PathBuf::from(valeur)),
+            autre => return Err(format!("Option inconnue : '{}'", autre)),
+        }
+    }
+    Ok(options)
 }
 
-println!("+---------+--------+-------------+----------+");
-println!("| Total   | {: <6} |             | {: <8} |", score_total, total_lancers);
-println!("+---------+--------+-------------+----------+");
+/// Lit l'adresse du serveur (port 4210 par défaut) et l'option `--nom` de la
+/// commande `rejoindre`.
+fn lire_options_client(args: impl Iterator<Item = String>) -> Result<(String, String), String> {
+    let mut adresse = None;
+    let mut nom = std::env::var("USER").unwrap_or_default();
+    let mut args = args;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--nom" => nom = args.next().ok_or("Valeur manquante pour --nom")?,
+            option if option.starts_with("--") => return Err(format!("Option inconnue : '{}'", option)),
+            _ if adresse.is_some() => return Err(format!("Argument en trop : '{}'", arg)),
+            _ => adresse = Some(arg),
+        }
+    }
+    let adresse = adresse.ok_or("Adresse du serveur manquante")?;
+    let adresse = if adresse.parse::<SocketAddr>().is_ok() {
+        adresse
+    } else if let Ok(ip) = adresse.parse::<IpAddr>() {
+        SocketAddr::new(ip, reseau::PORT).to_string()
+    } else if adresse.contains(':') {
+        // Nom d'hôte suivi de son port.
+        adresse
+    } else {
+        format!("{}:{}", adresse, reseau::PORT)
+    };
+    Ok((adresse, nom))
+}
 
-let nombre_parties = scores.len() as f32;
-let moyenne_points = score_total as f32 / nombre_parties;
-let moyenne_lancers = total_lancers as f32 / nombre_parties;
+/// Lit les options de la commande `simuler`.
+fn lire_options_simulation(args: impl Iterator<Item = String>) -> Result<OptionsSimulation, String> {
+    let mut options = OptionsSimulation::default();
+    let mut args = args;
+    while let Some(arg) = args.next() {
+        let mut valeur = || args.next().ok_or(format!("Valeur manquante pour {}", arg));
+        match arg.as_str() {
+            "--parties" => {
+                let valeur = valeur()?;
+                options.parties = valeur
+                    .parse()
+                    .ok()
+                    .filter(|&n| n > 0)
+                    .ok_or_else(|| format!("Nombre de parties invalide : '{}'", valeur))?;
+            }
+            "--lancers" => options.lancers = simulation::parse_lancers(&valeur()?)?,
+            "--strategie" => options.strategie = valeur()?.parse()?,
+            "--seed" => {
+                let valeur = valeur()?;
+                options.graine = Some(valeur.parse().map_err(|_| format!("Graine invalide : '{}'", valeur))?);
+            }
+            "--regles" => options.regles = Some(PathBuf::from(valeur()?)),
+            "--csv" => options.csv = Some(PathBuf::from(valeur()?)),
+            autre => return Err(format!("Option inconnue : '{}'", autre)),
+        }
+    }
+    Ok(options)
+}
 
-println!("\nStatistiques :");
-println!("Nombre de parties : {}", scores.len());
-println!("Moyenne de points par partie : {:.2}", moyenne_points);
-println!("Moyenne de lancers par partie : {:.2}", moyenne_lancers);
+/// Lit les options `--seed <n>`, `--regles <fichier>`, `--joueurs <n>`, `--ia <niveau>` et `--tui`.
+fn lire_options(args: impl Iterator<Item = String>) -> Result<Options, String> {
+    let mut options = Options::default();
+    let mut args = args;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--seed" => {
+                let valeur = args.next().ok_or("Valeur manquante pour --seed")?;
+                let n = valeur
+                    .parse()
+                    .map_err(|_| format!("Graine invalide : '{}'", valeur))?;
+                options.graine = Some(n);
+            }
+            "--regles" => {
+                let chemin = args.next().ok_or("Valeur manquante pour --regles")?;
+                options.regles = Some(PathBuf::from(chemin));
+            }
+            "--joueurs" => {
+                let valeur = args.next().ok_or("Valeur manquante pour --joueurs")?;
+                let n = valeur
+                    .parse()
+                    .map_err(|_| format!("Nombre de joueurs invalide : '{}'", valeur))?;
+                options.joueurs = Some(n);
+            }
+            "--nom" => {
+                let nom = args.next().ok_or("Valeur manquante pour --nom")?;
+                if nom.trim().is_empty() {
+                    return Err("Le nom du joueur ne peut pas être vide".to_string());
+                }
+                options.nom = Some(nom);
+            }
+            "--historique" => {
+                let chemin = args.next().ok_or("Valeur manquante pour --historique")?;
+                options.historique = Some(PathBuf::from(chemin));
+            }
+            "--ia" => {
+                let niveau = args.next().ok_or("Valeur manquante pour --ia")?;
+                options.ordinateurs.push(niveau.parse()?);
+            }
+            "--tui" => options.tui = true,
+            "--journal" => {
+                let chemin = args.next().ok_or("Valeur manquante pour --journal")?;
+                options.journal = Some(PathBuf::from(chemin));
+            }
+            autre => return Err(format!("Option inconnue : '{}'", autre)),
+        }
+    }
 
-if let Some(meilleur_score) = scores.iter().max_by_key(|s| s.points) {
-println!("Meilleur score : {} points (partie {})", meilleur_score.points, meilleur_score.partie);
+    let nombre = options.joueurs.unwrap_or(1) + options.ordinateurs.len();
+    let a_plusieurs = options.joueurs.is_some() || !options.ordinateurs.is_empty();
+    if a_plusieurs && options.tui {
+        return Err("L'interface plein écran (--tui) ne joue qu'en solo".to_string());
+    }
+    if a_plusieurs && options.journal.is_some() {
+        return Err("Seules les sessions solo s'enregistrent dans un journal (--journal)".to_string());
+    }
+    if a_plusieurs && !(MIN_JOUEURS..=MAX_JOUEURS).contains(&nombre) {
+        return Err(format!(
+            "Il faut entre {} et {} joueurs, ordinateurs compris ({} demandés)",
+            MIN_JOUEURS, MAX_JOUEURS, nombre
+        ));
+    }
+    Ok(options)
 }
-}
\ No newline at end of file
+
+// Déclaration du module de tests
+#[cfg(test)]
+#[path = "tests/console.rs"]
+mod tests;
@@ -0,0 +1,275 @@
+use std::sync::{Arc, OnceLock};
+
+use serde::{Deserialize, Serialize};
+
+use crate::des::SourceDes;
+use crate::regles::{trier_des, Regles};
+
+/// Nombre maximal de lancers par partie.
+pub const MAX_LANCERS: u32 = 15;
+
+/// Jetons d'une combinaison de trois dés selon la table traditionnelle.
+pub fn calculer_points(des: &[u8; 3]) -> u32 {
+    static TRADITIONNELLES: OnceLock<Regles> = OnceLock::new();
+    TRADITIONNELLES.get_or_init(Regles::traditionnelles).points(des)
+}
+
+/// Le 421 termine la partie, quelle que soit la table de règles.
+pub fn est_421(des: &[u8; 3]) -> bool {
+    trier_des(des) == [4, 2, 1]
+}
+
+/// Combinaison affichée : `"4 2 1"`.
+pub fn formater_des(des: &[u8; 3]) -> String {
+    format!("{} {} {}", des[0], des[1], des[2])
+}
+
+/// Étape d'une partie.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Etat {
+    /// Aucun dé n'a encore été lancé.
+    Nouvelle,
+    /// Des dés ont été lancés et il reste des lancers : relancer ou s'arrêter.
+    Decision,
+    /// La partie est finie (421, plus de lancers, ou arrêt du joueur).
+    Terminee,
+}
+
+/// Bilan d'une partie : la meilleure combinaison obtenue.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Resultat {
+    pub points: u32,
+    pub des: [u8; 3],
+    /// Lancers joués pendant la partie.
+    pub lancers: u32,
+    /// Lancer où la combinaison retenue est sortie.
+    pub obtenu_au: u32,
+}
+
+impl Resultat {
+    pub fn combinaison(&self) -> String {
+        formater_des(&self.des)
+    }
+}
+
+/// Ce qui s'est passé lors d'une action, à afficher par l'interface.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Evenement {
+    /// Résultat d'un lancer.
+    DesLances { lancer: u32, des: [u8; 3], points: u32 },
+    /// Le lancer est un 421.
+    Quatre21,
+    /// La partie est finie.
+    PartieTerminee(Resultat),
+}
+
+/// Une partie de 421 : machine à états pure, sans entrée/sortie.
+///
+/// ```
+/// use game_421::des::DesScriptes;
+/// use game_421::partie::{choisir_gardes, Etat, Partie};
+///
+/// let mut des = DesScriptes::new(vec![4, 5, 2, 1]);
+/// let mut partie = Partie::new(3).unwrap();
+/// partie.lancer(&mut des).unwrap();
+/// assert_eq!(partie.etat(), Etat::Decision);
+/// // On garde le 4 et le 2, seul le 5 est relancé.
+/// partie.garder(choisir_gardes(&partie.des(), &[4, 2]).unwrap()).unwrap();
+/// partie.lancer(&mut des).unwrap();
+/// assert_eq!(partie.des(), [4, 1, 2]);
+/// assert_eq!(partie.resultat().unwrap().points, 10);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Partie {
+    regles: Arc<Regles>,
+    max_lancers: u32,
+    lancers: u32,
+    des: [u8; 3],
+    /// Dés conservés lors du prochain lancer.
+    gardes: [bool; 3],
+    /// Meilleure combinaison ; son champ `lancers` n'est pas tenu à jour.
+    meilleur: Option<Resultat>,
+    etat: Etat,
+}
+
+impl Partie {
+    /// Nouvelle partie de `max_lancers` lancers au plus (1 à [`MAX_LANCERS`]),
+    /// avec la table traditionnelle.
+    pub fn new(max_lancers: u32) -> Result<Self, String> {
+        Partie::avec_regles(max_lancers, Arc::new(Regles::traditionnelles()))
+    }
+
+    /// Nouvelle partie jouée avec une table de règles maison.
+    pub fn avec_regles(max_lancers: u32, regles: Arc<Regles>) -> Result<Self, String> {
+        if !(1..=MAX_LANCERS).contains(&max_lancers) {
+            return Err(format!("Le nombre de lancers doit être entre 1 et {}", MAX_LANCERS));
+        }
+        Ok(Partie {
+            regles,
+            max_lancers,
+            lancers: 0,
+            des: [0; 3],
+            gardes: [false; 3],
+            meilleur: None,
+            etat: Etat::Nouvelle,
+        })
+    }
+
+    pub fn regles(&self) -> &Regles {
+        &self.regles
+    }
+
+    pub fn etat(&self) -> Etat {
+        self.etat
+    }
+
+    /// Dés du dernier lancer (`[0, 0, 0]` avant le premier).
+    pub fn des(&self) -> [u8; 3] {
+        self.des
+    }
+
+    /// Dés qui seront conservés au prochain lancer.
+    pub fn gardes(&self) -> [bool; 3] {
+        self.gardes
+    }
+
+    pub fn lancers(&self) -> u32 {
+        self.lancers
+    }
+
+    pub fn max_lancers(&self) -> u32 {
+        self.max_lancers
+    }
+
+    pub fn lancers_restants(&self) -> u32 {
+        self.max_lancers - self.lancers
+    }
+
+    /// Meilleure combinaison obtenue jusqu'ici, avec le nombre de lancers joués.
+    pub fn meilleur(&self) -> Option<Resultat> {
+        self.meilleur.map(|m| Resultat { lancers: self.lancers, ..m })
+    }
+
+    /// Résultat final, une fois la partie terminée.
+    pub fn resultat(&self) -> Option<Resultat> {
+        match self.etat {
+            Etat::Terminee => self.meilleur(),
+            _ => None,
+        }
+    }
+
+    /// Choisit les dés à conserver au prochain lancer ; les autres seront relancés.
+    /// Le choix ne vaut que pour un lancer.
+    pub fn garder(&mut self, gardes: [bool; 3]) -> Result<(), String> {
+        match self.etat {
+            Etat::Decision if gardes.iter().all(|&g| g) => {
+                Err("Gardez au plus deux dés pour relancer".to_string())
+            }
+            Etat::Decision => {
+                self.gardes = gardes;
+                Ok(())
+            }
+            Etat::Nouvelle => Err("Lancez les dés au moins une fois".to_string()),
+            Etat::Terminee => Err("La partie est terminée".to_string()),
+        }
+    }
+
+    /// Lance les trois dés, puis relance ceux qui ne sont pas gardés.
+    pub fn lancer(&mut self, source: &mut dyn SourceDes) -> Result<Vec<Evenement>, String> {
+        if self.etat == Etat::Terminee {
+            return Err("La partie est terminée".to_string());
+        }
+
+        for (de, garde) in self.des.iter_mut().zip(self.gardes) {
+            if !garde {
+                *de = source.lancer_de();
+            }
+        }
+        self.gardes = [false; 3];
+        self.lancers += 1;
+        let points = self.regles.points(&self.des);
+
+        // À force égale, la première combinaison obtenue est conservée.
+        if self.meilleur.is_none_or(|m| self.regles.comparer(&self.des, &m.des).is_gt()) {
+            self.meilleur = Some(Resultat {
+                points,
+                des: self.des,
+                lancers: self.lancers,
+                obtenu_au: self.lancers,
+            });
+        }
+
+        let mut evenements = vec![Evenement::DesLances { lancer: self.lancers, des: self.des, points }];
+        if est_421(&self.des) {
+            evenements.push(Evenement::Quatre21);
+            evenements.push(self.terminer());
+        } else if self.lancers == self.max_lancers {
+            evenements.push(self.terminer());
+        } else {
+            self.etat = Etat::Decision;
+        }
+        Ok(evenements)
+    }
+
+    /// Le joueur garde sa meilleure combinaison et s'arrête.
+    pub fn arreter(&mut self) -> Result<Evenement, String> {
+        match self.etat {
+            Etat::Decision => Ok(self.terminer()),
+            Etat::Nouvelle => Err("Lancez les dés au moins une fois".to_string()),
+            Etat::Terminee => Err("La partie est terminée".to_string()),
+        }
+    }
+
+    fn terminer(&mut self) -> Evenement {
+        self.etat = Etat::Terminee;
+        Evenement::PartieTerminee(self.meilleur().expect("au moins un lancer avant la fin"))
+    }
+}
+
+/// Traduit les valeurs à garder (`[4, 2]` pour « garder le 4 et le 2 ») en
+/// positions dans `des` ; chaque dé ne peut être gardé qu'une fois.
+pub fn choisir_gardes(des: &[u8; 3], valeurs: &[u8]) -> Result<[bool; 3], String> {
+    let mut gardes = [false; 3];
+    for &valeur in valeurs {
+        let position = (0..3)
+            .find(|&i| !gardes[i] && des[i] == valeur)
+            .ok_or_else(|| format!("Aucun dé {} disponible parmi {}", valeur, formater_des(des)))?;
+        gardes[position] = true;
+    }
+    Ok(gardes)
+}
+
+/// Ligne du tableau des scores, conservée dans l'historique.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Score {
+    pub partie: u32,
+    pub points: u32,
+    pub combinaison: String,
+    pub lancers: u32,
+    /// Lancer où la combinaison retenue est sortie.
+    pub lancer_record: u32,
+    /// Début et fin de la partie, en secondes depuis l'époque Unix.
+    pub debut: u64,
+    pub fin: u64,
+}
+
+impl Score {
+    pub fn new(partie: u32, resultat: &Resultat, debut: u64, fin: u64) -> Self {
+        Score {
+            partie,
+            points: resultat.points,
+            combinaison: resultat.combinaison(),
+            lancers: resultat.lancers,
+            lancer_record: resultat.obtenu_au,
+            debut,
+            fin,
+        }
+    }
+
+    /// La partie s'est terminée sur un 421.
+    pub fn est_421(&self) -> bool {
+        let mut valeurs: Vec<&str> = self.combinaison.split_whitespace().collect();
+        valeurs.sort_unstable();
+        valeurs == ["1", "2", "4"]
+    }
+}
@@ -0,0 +1,215 @@
+use std::cmp::Ordering;
+use std::collections::HashSet;
+use std::fmt;
+use std::fs;
+use std::path::Path;
+use std::str::FromStr;
+
+/// Table traditionnelle, dans le format accepté par [`Regles::from_str`].
+pub const TABLE_TRADITIONNELLE: &str = "\
+# Règles du 421 : une combinaison par ligne, de la plus forte à la plus faible.
+# <dés> <jetons> [nom] ; « * » place les combinaisons ordinaires dans le classement.
+421 10 Quatre-cent-vingt-et-un
+111 7 Mac
+611 6 Fiche de 6
+666 6 Brelan de 6
+511 5 Fiche de 5
+555 5 Brelan de 5
+411 4 Fiche de 4
+444 4 Brelan de 4
+311 3 Fiche de 3
+333 3 Brelan de 3
+211 2 Fiche de 2
+222 2 Brelan de 2
+654 2 Suite 456
+543 2 Suite 345
+432 2 Suite 234
+321 2 Suite 123
+* 1 Ordinaire
+221 2 Nénette
+";
+
+/// Une combinaison de la table et sa valeur en jetons.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Regle {
+    /// Dés triés par valeur décroissante.
+    pub des: [u8; 3],
+    pub points: u32,
+    pub nom: String,
+}
+
+/// Force d'une combinaison : la plus grande l'emporte. Deux combinaisons
+/// ordinaires se départagent par leurs dés, du plus fort au plus faible.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Force(u32, [u8; 3]);
+
+/// Table des combinaisons : leur classement et leur valeur.
+///
+/// ```
+/// use game_421::regles::Regles;
+///
+/// let regles: Regles = "421 8\n* 1\n221 2 Nénette".parse().unwrap();
+/// assert_eq!(regles.points(&[1, 2, 4]), 8);
+/// assert_eq!(regles.nom(&[2, 1, 2]), "Nénette");
+/// assert!(regles.force(&[6, 5, 3]) > regles.force(&[2, 2, 1]));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Regles {
+    /// Combinaisons nommées, de la plus forte à la plus faible.
+    combinaisons: Vec<Regle>,
+    /// Nombre de combinaisons nommées plus fortes que les ordinaires.
+    rang_ordinaires: usize,
+    points_ordinaires: u32,
+    nom_ordinaires: String,
+}
+
+impl Default for Regles {
+    fn default() -> Self {
+        Regles::traditionnelles()
+    }
+}
+
+impl Regles {
+    /// Classement traditionnel : 421, Mac, fiches et brelans, suites, ordinaires, nénette.
+    pub fn traditionnelles() -> Self {
+        TABLE_TRADITIONNELLE.parse().expect("table traditionnelle valide")
+    }
+
+    /// Charge une table de règles maison depuis un fichier texte.
+    pub fn charger(chemin: &Path) -> Result<Self, String> {
+        let texte = fs::read_to_string(chemin)
+            .map_err(|e| format!("Impossible de lire '{}' : {}", chemin.display(), e))?;
+        texte
+            .parse()
+            .map_err(|e| format!("{} : {}", chemin.display(), e))
+    }
+
+    pub fn combinaisons(&self) -> &[Regle] {
+        &self.combinaisons
+    }
+
+    fn trouver(&self, des: &[u8; 3]) -> Option<(usize, &Regle)> {
+        let cle = trier_des(des);
+        self.combinaisons.iter().enumerate().find(|(_, r)| r.des == cle)
+    }
+
+    /// Jetons rapportés par une combinaison ordinaire.
+    pub fn points_ordinaires(&self) -> u32 {
+        self.points_ordinaires
+    }
+
+    /// Jetons rapportés par la combinaison, dans n'importe quel ordre.
+    pub fn points(&self, des: &[u8; 3]) -> u32 {
+        self.trouver(des).map_or(self.points_ordinaires, |(_, r)| r.points)
+    }
+
+    pub fn nom(&self, des: &[u8; 3]) -> &str {
+        self.trouver(des).map_or(&self.nom_ordinaires, |(_, r)| &r.nom)
+    }
+
+    pub fn force(&self, des: &[u8; 3]) -> Force {
+        // Rang 0 pour la plus forte ; les ordinaires occupent le rang `rang_ordinaires`.
+        let (rang, departage) = match self.trouver(des) {
+            Some((i, _)) if i < self.rang_ordinaires => (i, [0; 3]),
+            Some((i, _)) => (i + 1, [0; 3]),
+            None => (self.rang_ordinaires, trier_des(des)),
+        };
+        Force((self.combinaisons.len() - rang) as u32, departage)
+    }
+
+    /// Compare deux combinaisons : `Greater` si `a` bat `b`.
+    pub fn comparer(&self, a: &[u8; 3], b: &[u8; 3]) -> Ordering {
+        self.force(a).cmp(&self.force(b))
+    }
+}
+
+/// Dés triés par valeur décroissante : `[1, 4, 2]` devient `[4, 2, 1]`.
+pub fn trier_des(des: &[u8; 3]) -> [u8; 3] {
+    let mut tries = *des;
+    tries.sort_unstable_by(|a, b| b.cmp(a));
+    tries
+}
+
+fn lire_des(texte: &str) -> Result<[u8; 3], String> {
+    let chiffres: Vec<u8> = texte.bytes().map(|b| b.wrapping_sub(b'0')).collect();
+    match chiffres[..] {
+        [a, b, c] if chiffres.iter().all(|d| (1..=6).contains(d)) => Ok(trier_des(&[a, b, c])),
+        _ => Err(format!("Combinaison invalide : '{}' (trois chiffres de 1 à 6)", texte)),
+    }
+}
+
+impl FromStr for Regles {
+    type Err = String;
+
+    /// Une combinaison par ligne : `<dés> <jetons> [nom]`, de la plus forte à
+    /// la plus faible. La ligne `* <jetons> [nom]` donne la valeur et le rang
+    /// des combinaisons ordinaires (par défaut : 1 jeton, au bas du classement).
+    /// Les lignes vides et celles commençant par `#` sont ignorées.
+    fn from_str(texte: &str) -> Result<Self, Self::Err> {
+        let mut regles = Regles {
+            combinaisons: Vec::new(),
+            rang_ordinaires: usize::MAX,
+            points_ordinaires: 1,
+            nom_ordinaires: "Ordinaire".to_string(),
+        };
+        let mut vues = HashSet::new();
+
+        for (numero, ligne) in texte.lines().enumerate() {
+            let ligne = ligne.trim();
+            if ligne.is_empty() || ligne.starts_with('#') {
+                continue;
+            }
+            let erreur = |message: String| format!("ligne {} : {}", numero + 1, message);
+
+            let mut champs = ligne.splitn(3, char::is_whitespace);
+            let des = champs.next().unwrap_or_default();
+            let points = champs.next().ok_or_else(|| erreur("nombre de jetons manquant".to_string()))?;
+            let points = points
+                .parse()
+                .map_err(|_| erreur(format!("nombre de jetons invalide : '{}'", points)))?;
+            let nom = champs.next().map(str::trim).unwrap_or_default();
+
+            if des == "*" {
+                if regles.rang_ordinaires != usize::MAX {
+                    return Err(erreur("combinaisons ordinaires déjà classées".to_string()));
+                }
+                regles.rang_ordinaires = regles.combinaisons.len();
+                regles.points_ordinaires = points;
+                if !nom.is_empty() {
+                    regles.nom_ordinaires = nom.to_string();
+                }
+                continue;
+            }
+
+            let des = lire_des(des).map_err(erreur)?;
+            if !vues.insert(des) {
+                return Err(erreur(format!("combinaison {}{}{} en double", des[0], des[1], des[2])));
+            }
+            let nom = match nom {
+                "" => format!("{}{}{}", des[0], des[1], des[2]),
+                nom => nom.to_string(),
+            };
+            regles.combinaisons.push(Regle { des, points, nom });
+        }
+
+        regles.rang_ordinaires = regles.rang_ordinaires.min(regles.combinaisons.len());
+        Ok(regles)
+    }
+}
+
+impl fmt::Display for Regles {
+    /// Réécrit la table dans le format lu par [`Regles::from_str`].
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, regle) in self.combinaisons.iter().enumerate() {
+            if i == self.rang_ordinaires {
+                writeln!(f, "* {} {}", self.points_ordinaires, self.nom_ordinaires)?;
+            }
+            let [a, b, c] = regle.des;
+            writeln!(f, "{}{}{} {} {}", a, b, c, regle.points, regle.nom)?;
+        }
+        if self.rang_ordinaires == self.combinaisons.len() {
+            writeln!(f, "* {} {}", self.points_ordinaires, self.nom_ordinaires)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,261 @@
+use std::io::{self, BufRead, BufReader, Write};
+use std::net::{Shutdown, TcpListener, TcpStream};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+use crate::des::SourceDes;
+use crate::partie::Etat;
+use crate::regles::Regles;
+use crate::table::{EvenementTable, Joueur, Table};
+
+/// Port d'écoute par défaut du serveur.
+pub const PORT: u16 = 4210;
+
+/// Délai laissé à un client qui vient de se connecter pour se présenter
+/// ([`Requete::Rejoindre`]) ; passé ce délai, la connexion est refusée.
+pub const DELAI_PRESENTATION: Duration = Duration::from_secs(10);
+
+/// Message d'un client au serveur, une ligne JSON par message.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Requete {
+    /// Premier message : prendre place à la table.
+    Rejoindre { nom: String },
+    /// Relancer les dés non gardés (au plus deux dés gardés).
+    Lancer { gardes: [bool; 3] },
+    /// S'arrêter sur la combinaison obtenue.
+    Passer,
+    /// Demander les jetons de chacun, à tout moment.
+    Scores,
+}
+
+/// Message du serveur aux clients, une ligne JSON par message.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Message {
+    /// Place attribuée au client, en attendant les autres joueurs.
+    Bienvenue { joueur: usize, attendus: usize },
+    /// Tous les joueurs sont là : la charge commence.
+    Debut { joueurs: Vec<String>, max_lancers: u32 },
+    /// Début du tour d'un joueur.
+    Tour { joueur: usize, max_lancers: u32 },
+    /// Ce qui s'est passé à la table, envoyé à tous.
+    Evenement { evenement: EvenementTable },
+    /// Au joueur courant seulement : relancer ou passer.
+    Decision { des: [u8; 3], lancers_restants: u32 },
+    /// Jetons de chacun et du pot, en réponse à [`Requete::Scores`] ou après une manche.
+    Scores { pot: u32, joueurs: Vec<Joueur> },
+    /// Requête refusée ; elle n'a rien changé à la partie.
+    Erreur { message: String },
+    /// Un joueur s'est déconnecté : la partie s'arrête.
+    Abandon { joueur: usize },
+}
+
+/// Écrit un message sur une ligne.
+pub fn envoyer<W: Write, T: Serialize>(sortie: &mut W, message: &T) -> io::Result<()> {
+    let mut ligne = serde_json::to_string(message).map_err(io::Error::other)?;
+    ligne.push('\n');
+    sortie.write_all(ligne.as_bytes())?;
+    sortie.flush()
+}
+
+/// Lit le message de la ligne suivante ; `None` à la fin de la connexion.
+/// Une ligne illisible donne une erreur `InvalidData`.
+pub fn recevoir<R: BufRead, T: DeserializeOwned>(entree: &mut R) -> io::Result<Option<T>> {
+    let mut ligne = String::new();
+    if entree.read_line(&mut ligne)? == 0 {
+        return Ok(None);
+    }
+    serde_json::from_str(&ligne)
+        .map(Some)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, format!("Message invalide : {}", e)))
+}
+
+/// Requête lue par le fil d'un client : `None` quand il s'est déconnecté.
+type Lecture = (usize, Option<Result<Requete, String>>);
+
+/// Serveur d'une table de 421 en réseau.
+///
+/// Le serveur tient la [`Table`] et lance lui-même les dés : les clients
+/// n'envoient que leurs décisions, et celles qui arrivent hors de leur tour
+/// sont refusées. Chaque client est lu par son propre fil, qui transmet ses
+/// requêtes au serveur dans l'ordre d'arrivée.
+pub struct Serveur {
+    table: Table,
+    clients: Vec<TcpStream>,
+    requetes: Receiver<Lecture>,
+}
+
+impl Serveur {
+    /// Attend `nombre` joueurs sur `ecoute`, puis annonce le début de la partie.
+    /// Un client qui ne se présente pas dans le `delai` (en général
+    /// [`DELAI_PRESENTATION`]) est déconnecté, pour ne pas bloquer les autres.
+    pub fn accueillir(
+        ecoute: &TcpListener,
+        nombre: usize,
+        max_lancers: u32,
+        regles: Arc<Regles>,
+        delai: Duration,
+    ) -> io::Result<Self> {
+        // Vérifie le nombre de joueurs et de lancers avant d'accepter quiconque.
+        let noms = (1..=nombre).map(|i| format!("Joueur {}", i)).collect();
+        Table::new(noms, max_lancers, Arc::clone(&regles)).map_err(io::Error::other)?;
+
+        let (envoi, requetes) = mpsc::channel();
+        let mut clients = Vec::new();
+        let mut noms = Vec::new();
+        while clients.len() < nombre {
+            let (mut flux, _) = ecoute.accept()?;
+            flux.set_read_timeout(Some(delai))?;
+            let mut lecture = BufReader::new(flux.try_clone()?);
+            let joueur = clients.len();
+            let presentation = recevoir(&mut lecture);
+            flux.set_read_timeout(None)?;
+            let nom = match presentation {
+                Ok(Some(Requete::Rejoindre { nom })) if !nom.trim().is_empty() => nom.trim().to_string(),
+                Ok(Some(Requete::Rejoindre { .. })) => format!("Joueur {}", joueur + 1),
+                // Connexion refusée : le client ne s'est pas présenté à temps.
+                _ => {
+                    let _ = flux.shutdown(Shutdown::Both);
+                    continue;
+                }
+            };
+            envoyer(&mut flux, &Message::Bienvenue { joueur, attendus: nombre })?;
+            lire_requetes(joueur, lecture, envoi.clone());
+            clients.push(flux);
+            noms.push(nom);
+        }
+
+        let table = Table::new(noms.clone(), max_lancers, regles).map_err(io::Error::other)?;
+        let mut serveur = Serveur { table, clients, requetes };
+        serveur.diffuser(&Message::Debut { joueurs: noms, max_lancers })?;
+        Ok(serveur)
+    }
+
+    pub fn table(&self) -> &Table {
+        &self.table
+    }
+
+    /// Joue la partie jusqu'au bout et renvoie le perdant. S'arrête sur une
+    /// erreur si un joueur se déconnecte.
+    pub fn jouer(&mut self, source: &mut dyn SourceDes) -> io::Result<usize> {
+        while !self.table.terminee() {
+            let joueur = self.table.joueur_courant();
+            let max_lancers = self.table.partie().max_lancers();
+            self.diffuser(&Message::Tour { joueur, max_lancers })?;
+            let evenements = self.table.lancer(source).map_err(io::Error::other)?;
+            self.diffuser_evenements(&evenements)?;
+
+            while !self.table.terminee() && self.table.partie().etat() == Etat::Decision {
+                let partie = self.table.partie();
+                let decision = Message::Decision { des: partie.des(), lancers_restants: partie.lancers_restants() };
+                self.envoyer_a(joueur, &decision)?;
+                let resultat = match self.attendre(joueur)? {
+                    Requete::Lancer { gardes } => self.table.garder(gardes).and_then(|()| self.table.lancer(source)),
+                    _ => self.table.arreter(),
+                };
+                match resultat {
+                    Ok(evenements) => self.diffuser_evenements(&evenements)?,
+                    Err(message) => self.envoyer_a(joueur, &Message::Erreur { message })?,
+                }
+            }
+        }
+        Ok(self.table.perdant().expect("partie terminée"))
+    }
+
+    /// Attend la décision (lancer ou passer) de `joueur`, en répondant aux
+    /// autres requêtes en attendant.
+    fn attendre(&mut self, joueur: usize) -> io::Result<Requete> {
+        loop {
+            let (client, lecture) = self.requetes.recv().map_err(io::Error::other)?;
+            let reponse = match lecture {
+                None => {
+                    self.abandon(client);
+                    let nom = &self.table.joueurs()[client].nom;
+                    return Err(io::Error::new(
+                        io::ErrorKind::ConnectionAborted,
+                        format!("{} s'est déconnecté", nom),
+                    ));
+                }
+                Some(Err(message)) => Message::Erreur { message },
+                Some(Ok(Requete::Scores)) => self.scores(),
+                Some(Ok(Requete::Rejoindre { .. })) => Message::Erreur {
+                    message: "Vous êtes déjà à la table".to_string(),
+                },
+                Some(Ok(_)) if client != joueur => Message::Erreur {
+                    message: format!("Ce n'est pas votre tour : à {} de jouer", self.table.joueurs()[joueur].nom),
+                },
+                Some(Ok(requete)) => return Ok(requete),
+            };
+            self.envoyer_a(client, &reponse)?;
+        }
+    }
+
+    fn scores(&self) -> Message {
+        Message::Scores { pot: self.table.pot(), joueurs: self.table.joueurs().to_vec() }
+    }
+
+    /// Envoie les événements à tous, avec les jetons de chacun après une manche.
+    fn diffuser_evenements(&mut self, evenements: &[EvenementTable]) -> io::Result<()> {
+        for &evenement in evenements {
+            self.diffuser(&Message::Evenement { evenement })?;
+            if let EvenementTable::Manche { .. } = evenement {
+                self.diffuser(&self.scores())?;
+            }
+        }
+        Ok(())
+    }
+
+    fn diffuser(&mut self, message: &Message) -> io::Result<()> {
+        for joueur in 0..self.clients.len() {
+            self.envoyer_a(joueur, message)?;
+        }
+        Ok(())
+    }
+
+    /// Envoie un message à un joueur ; s'il n'est plus joignable, les autres
+    /// sont prévenus que la partie s'arrête.
+    fn envoyer_a(&mut self, joueur: usize, message: &Message) -> io::Result<()> {
+        let resultat = envoyer(&mut self.clients[joueur], message);
+        if resultat.is_err() {
+            self.abandon(joueur);
+        }
+        resultat
+    }
+
+    fn abandon(&mut self, joueur: usize) {
+        for (i, flux) in self.clients.iter_mut().enumerate() {
+            if i != joueur {
+                // Les autres clients peuvent être partis eux aussi.
+                let _ = envoyer(flux, &Message::Abandon { joueur });
+            }
+        }
+    }
+}
+
+impl Drop for Serveur {
+    /// Ferme les connexions, ce qui termine aussi les fils de lecture.
+    fn drop(&mut self) {
+        for flux in &self.clients {
+            let _ = flux.shutdown(Shutdown::Both);
+        }
+    }
+}
+
+/// Lit les requêtes d'un client dans un fil dédié, jusqu'à sa déconnexion.
+fn lire_requetes(joueur: usize, mut lecture: BufReader<TcpStream>, envoi: Sender<Lecture>) {
+    thread::spawn(move || loop {
+        let requete = match recevoir(&mut lecture) {
+            Ok(Some(requete)) => Some(Ok(requete)),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData => Some(Err(e.to_string())),
+            Ok(None) | Err(_) => None,
+        };
+        let fin = requete.is_none();
+        if envoi.send((joueur, requete)).is_err() || fin {
+            break;
+        }
+    });
+}
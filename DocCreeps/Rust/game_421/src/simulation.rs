@@ -0,0 +1,205 @@
+use std::collections::BTreeMap;
+use std::io::{self, Write};
+use std::ops::RangeInclusive;
+use std::sync::Arc;
+
+use crate::des::DesAleatoires;
+use crate::ia::{Comptage, Niveau, Ordinateur};
+use crate::partie::{est_421, Etat, Partie, MAX_LANCERS};
+use crate::regles::Regles;
+
+/// Paramètres d'une simulation sans interface.
+#[derive(Debug, Clone)]
+pub struct ConfigSimulation {
+    /// Parties jouées pour chaque nombre de lancers.
+    pub parties: u64,
+    /// Nombres de lancers par partie à essayer (entre 1 et [`MAX_LANCERS`]).
+    pub lancers: RangeInclusive<u32>,
+    pub strategie: Niveau,
+    pub graine: u64,
+    pub regles: Arc<Regles>,
+}
+
+/// Bilan des parties jouées avec un même nombre de lancers.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RapportSimulation {
+    pub lancers: u32,
+    pub parties: u64,
+    /// Somme des points retenus par partie.
+    pub points: u64,
+    /// Nombre de parties par combinaison retenue (nom de la table de règles).
+    pub combinaisons: BTreeMap<String, u64>,
+    /// `parties_421[k]` : parties où le 421 est sorti au lancer `k + 1`.
+    pub parties_421: Vec<u64>,
+}
+
+impl RapportSimulation {
+    pub fn score_moyen(&self) -> f64 {
+        self.points as f64 / self.parties as f64
+    }
+
+    pub fn probabilite(&self, combinaison: &str) -> f64 {
+        self.combinaisons.get(combinaison).copied().unwrap_or(0) as f64 / self.parties as f64
+    }
+
+    /// Parties sans 421.
+    pub fn sans_421(&self) -> u64 {
+        self.parties - self.parties_421.iter().sum::<u64>()
+    }
+}
+
+/// Parse `"3"` ou `"1-15"` en plage de nombres de lancers.
+pub fn parse_lancers(texte: &str) -> Result<RangeInclusive<u32>, String> {
+    let lire = |s: &str| {
+        s.trim()
+            .parse::<u32>()
+            .ok()
+            .filter(|n| (1..=MAX_LANCERS).contains(n))
+            .ok_or_else(|| format!("Nombre de lancers invalide : '{}' (entre 1 et {})", s, MAX_LANCERS))
+    };
+    let (debut, fin) = match texte.split_once('-') {
+        Some((debut, fin)) => (lire(debut)?, lire(fin)?),
+        None => (lire(texte)?, lire(texte)?),
+    };
+    if debut > fin {
+        return Err(format!("Plage de lancers vide : '{}'", texte));
+    }
+    Ok(debut..=fin)
+}
+
+/// Joue `config.parties` parties pour chaque nombre de lancers, avec la
+/// stratégie choisie. Comme en solo, chaque partie compte sa meilleure
+/// combinaison, et les ordinateurs jouent pour elle ([`Comptage::Meilleure`]) :
+/// le score moyen est celui de la stratégie sous ce comptage. Chaque nombre
+/// de lancers a sa propre suite de dés, dérivée de la graine : les résultats
+/// sont reproductibles.
+pub fn simuler(config: &ConfigSimulation) -> Vec<RapportSimulation> {
+    config.lancers.clone().map(|lancers| simuler_lancers(config, lancers)).collect()
+}
+
+fn simuler_lancers(config: &ConfigSimulation, max_lancers: u32) -> RapportSimulation {
+    let graine = config.graine.wrapping_add(max_lancers as u64);
+    let mut des = DesAleatoires::new(graine);
+    let mut ordinateur = Ordinateur::new(config.strategie, Arc::clone(&config.regles), Comptage::Meilleure, graine);
+    let mut rapport = RapportSimulation {
+        lancers: max_lancers,
+        parties: config.parties,
+        points: 0,
+        combinaisons: BTreeMap::new(),
+        parties_421: vec![0; max_lancers as usize],
+    };
+
+    for _ in 0..config.parties {
+        let mut partie =
+            Partie::avec_regles(max_lancers, Arc::clone(&config.regles)).expect("nombre de lancers valide");
+        partie.lancer(&mut des).expect("partie en cours");
+        while partie.etat() == Etat::Decision {
+            match ordinateur.decider(&partie) {
+                Some(gardes) => {
+                    partie.garder(gardes).expect("au plus deux dés gardés");
+                    partie.lancer(&mut des).expect("partie en cours");
+                }
+                None => {
+                    partie.arreter().expect("partie en cours");
+                }
+            }
+        }
+
+        let resultat = partie.resultat().expect("partie terminée");
+        rapport.points += resultat.points as u64;
+        *rapport
+            .combinaisons
+            .entry(config.regles.nom(&resultat.des).to_string())
+            .or_insert(0) += 1;
+        if est_421(&resultat.des) {
+            rapport.parties_421[resultat.lancers as usize - 1] += 1;
+        }
+    }
+    rapport
+}
+
+/// Écrit les rapports en CSV « long » : `lancers,mesure,cle,valeur`, avec
+/// les mesures `score_moyen`, `combinaison` (probabilité de chaque combinaison
+/// retenue) et `lancers_421` (probabilité d'obtenir le 421 au lancer `cle`,
+/// `aucun` s'il ne sort pas).
+pub fn ecrire_csv<W: Write>(rapports: &[RapportSimulation], sortie: &mut W) -> io::Result<()> {
+    writeln!(sortie, "lancers,mesure,cle,valeur")?;
+    for rapport in rapports {
+        let parties = rapport.parties as f64;
+        writeln!(sortie, "{},score_moyen,,{:.6}", rapport.lancers, rapport.score_moyen())?;
+        for (nom, &nombre) in &rapport.combinaisons {
+            writeln!(sortie, "{},combinaison,{},{:.6}", rapport.lancers, nom, nombre as f64 / parties)?;
+        }
+        for (k, &nombre) in rapport.parties_421.iter().enumerate() {
+            writeln!(sortie, "{},lancers_421,{},{:.6}", rapport.lancers, k + 1, nombre as f64 / parties)?;
+        }
+        writeln!(sortie, "{},lancers_421,aucun,{:.6}", rapport.lancers, rapport.sans_421() as f64 / parties)?;
+    }
+    Ok(())
+}
+
+/// Tableau du score moyen et de la probabilité de 421 par nombre de lancers.
+pub fn formater_resume(rapports: &[RapportSimulation]) -> String {
+    let mut texte = String::new();
+    texte.push_str("+---------+-------------+---------------+\n");
+    texte.push_str("| Lancers | Score moyen | 421 obtenu    |\n");
+    texte.push_str("+---------+-------------+---------------+\n");
+    for rapport in rapports {
+        let avec_421 = 1.0 - rapport.sans_421() as f64 / rapport.parties as f64;
+        texte.push_str(&format!(
+            "| {: <7} | {: <11.4} | {: <13} |\n",
+            rapport.lancers,
+            rapport.score_moyen(),
+            format!("{:.2}%", avec_421 * 100.0)
+        ));
+    }
+    texte.push_str("+---------+-------------+---------------+\n");
+    texte
+}
+
+/// Histogrammes d'un rapport : combinaisons retenues, puis lancer du 421.
+pub fn histogramme(rapport: &RapportSimulation, largeur: usize) -> String {
+    let parties = rapport.parties as f64;
+    let mut combinaisons: Vec<(&String, &u64)> = rapport.combinaisons.iter().collect();
+    combinaisons.sort_by(|a, b| b.1.cmp(a.1).then(a.0.cmp(b.0)));
+
+    let mut texte = format!(
+        "Combinaisons retenues ({} lancer(s), {} parties) :\n",
+        rapport.lancers, rapport.parties
+    );
+    let lignes: Vec<(String, f64)> = combinaisons
+        .into_iter()
+        .map(|(nom, &nombre)| (nom.clone(), nombre as f64 / parties))
+        .collect();
+    texte.push_str(&barres(&lignes, largeur));
+
+    texte.push_str("Lancer où sort le 421 :\n");
+    let mut lignes: Vec<(String, f64)> = rapport
+        .parties_421
+        .iter()
+        .enumerate()
+        .map(|(k, &nombre)| (format!("{}", k + 1), nombre as f64 / parties))
+        .collect();
+    lignes.push(("aucun".to_string(), rapport.sans_421() as f64 / parties));
+    texte.push_str(&barres(&lignes, largeur));
+    texte
+}
+
+/// Une barre par ligne, la plus grande probabilité occupant `largeur` caractères.
+fn barres(lignes: &[(String, f64)], largeur: usize) -> String {
+    let max = lignes.iter().map(|(_, p)| *p).fold(0.0, f64::max);
+    let etiquette = lignes.iter().map(|(nom, _)| nom.chars().count()).max().unwrap_or(0);
+    let mut texte = String::new();
+    for (nom, probabilite) in lignes {
+        let longueur = if max > 0.0 { (probabilite / max * largeur as f64).round() as usize } else { 0 };
+        let marge = etiquette - nom.chars().count();
+        texte.push_str(&format!(
+            "  {}{} | {} {:.2}%\n",
+            nom,
+            " ".repeat(marge),
+            "#".repeat(longueur),
+            probabilite * 100.0
+        ));
+    }
+    texte
+}
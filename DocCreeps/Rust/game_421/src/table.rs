@@ -0,0 +1,243 @@
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+
+use crate::des::SourceDes;
+use crate::partie::{Etat, Evenement, Partie};
+use crate::regles::Regles;
+
+/// Jetons mis en jeu au début d'une partie à plusieurs.
+pub const JETONS: u32 = 21;
+
+/// Nombre de joueurs autour de la table.
+pub const MIN_JOUEURS: usize = 2;
+pub const MAX_JOUEURS: usize = 8;
+
+/// Phase de la partie à plusieurs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Phase {
+    /// Les jetons du pot sont distribués : un seul lancer par joueur.
+    Charge,
+    /// Les joueurs se débarrassent de leurs jetons ; le meneur fixe le nombre de lancers.
+    Decharge,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Joueur {
+    pub nom: String,
+    pub jetons: u32,
+}
+
+/// Ce qui s'est passé autour de la table, à afficher par l'interface.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum EvenementTable {
+    /// Lancer du joueur courant (résultat ou 421).
+    Jeu(Evenement),
+    /// Le joueur a fini son tour ; c'est sa dernière combinaison qui compte.
+    TourTermine { joueur: usize, des: [u8; 3], lancers: u32 },
+    /// Bilan d'une manche : `jetons` passent au perdant, depuis le pot (charge)
+    /// ou depuis le gagnant (décharge).
+    Manche { phase: Phase, gagnant: usize, perdant: usize, jetons: u32 },
+    /// Le pot est vide : début de la décharge.
+    Decharge,
+    /// Un joueur a tous les jetons : il a perdu la partie.
+    FinDePartie { perdant: usize },
+}
+
+/// Partie de 421 à plusieurs, en tour par tour sur le même écran.
+///
+/// Pendant la charge, chaque joueur lance une fois et le plus faible prend au
+/// pot autant de jetons que vaut la meilleure combinaison. Quand le pot est
+/// vide, la décharge commence : le meneur joue jusqu'à `max_lancers` lancers et
+/// les suivants ne peuvent pas en faire plus que lui ; le gagnant de la manche
+/// donne au perdant autant de jetons que vaut sa combinaison. Les joueurs sans
+/// jeton ne jouent plus, le perdant d'une manche mène la suivante, et la partie
+/// s'arrête quand un joueur a tous les jetons.
+///
+/// À égalité, le premier à avoir joué l'emporte et le dernier perd.
+#[derive(Debug, Clone)]
+pub struct Table {
+    regles: Arc<Regles>,
+    joueurs: Vec<Joueur>,
+    pot: u32,
+    phase: Phase,
+    max_lancers: u32,
+    /// Joueurs de la manche en cours, dans l'ordre de jeu (le meneur d'abord).
+    ordre: Vec<usize>,
+    /// Combinaisons finales des joueurs ayant déjà joué la manche.
+    combinaisons: Vec<(usize, [u8; 3])>,
+    partie: Partie,
+    perdant: Option<usize>,
+}
+
+impl Table {
+    /// Nouvelle table : tous les jetons sont dans le pot, le premier joueur mène.
+    pub fn new(noms: Vec<String>, max_lancers: u32, regles: Arc<Regles>) -> Result<Self, String> {
+        if !(MIN_JOUEURS..=MAX_JOUEURS).contains(&noms.len()) {
+            return Err(format!("Il faut entre {} et {} joueurs", MIN_JOUEURS, MAX_JOUEURS));
+        }
+        // Vérifie `max_lancers` une fois pour toutes.
+        Partie::avec_regles(max_lancers, Arc::clone(&regles))?;
+
+        let joueurs: Vec<Joueur> = noms.into_iter().map(|nom| Joueur { nom, jetons: 0 }).collect();
+        let ordre = (0..joueurs.len()).collect();
+        let partie = Partie::avec_regles(1, Arc::clone(&regles))?;
+        Ok(Table {
+            regles,
+            joueurs,
+            pot: JETONS,
+            phase: Phase::Charge,
+            max_lancers,
+            ordre,
+            combinaisons: Vec::new(),
+            partie,
+            perdant: None,
+        })
+    }
+
+    pub fn joueurs(&self) -> &[Joueur] {
+        &self.joueurs
+    }
+
+    pub fn pot(&self) -> u32 {
+        self.pot
+    }
+
+    pub fn phase(&self) -> Phase {
+        self.phase
+    }
+
+    /// Tour du joueur courant.
+    pub fn partie(&self) -> &Partie {
+        &self.partie
+    }
+
+    pub fn joueur_courant(&self) -> usize {
+        self.ordre[self.combinaisons.len()]
+    }
+
+    /// Le joueur qui a tous les jetons, une fois la partie finie.
+    pub fn perdant(&self) -> Option<usize> {
+        self.perdant
+    }
+
+    pub fn terminee(&self) -> bool {
+        self.perdant.is_some()
+    }
+
+    /// Lance les dés du joueur courant (voir [`Partie::lancer`]).
+    pub fn lancer(&mut self, source: &mut dyn SourceDes) -> Result<Vec<EvenementTable>, String> {
+        self.verifier_en_cours()?;
+        let evenements = self.partie.lancer(source)?;
+        Ok(self.suivre(evenements))
+    }
+
+    /// Choisit les dés à garder au prochain lancer (voir [`Partie::garder`]).
+    pub fn garder(&mut self, gardes: [bool; 3]) -> Result<(), String> {
+        self.verifier_en_cours()?;
+        self.partie.garder(gardes)
+    }
+
+    /// Le joueur courant s'arrête sur sa combinaison.
+    pub fn arreter(&mut self) -> Result<Vec<EvenementTable>, String> {
+        self.verifier_en_cours()?;
+        let evenement = self.partie.arreter()?;
+        Ok(self.suivre(vec![evenement]))
+    }
+
+    fn verifier_en_cours(&self) -> Result<(), String> {
+        match self.perdant {
+            Some(_) => Err("La partie est terminée".to_string()),
+            None => Ok(()),
+        }
+    }
+
+    /// Traduit les événements du tour et passe au joueur suivant si le tour est fini.
+    fn suivre(&mut self, evenements: Vec<Evenement>) -> Vec<EvenementTable> {
+        let mut sortie: Vec<EvenementTable> = evenements
+            .into_iter()
+            .filter(|e| !matches!(e, Evenement::PartieTerminee(_)))
+            .map(EvenementTable::Jeu)
+            .collect();
+        if self.partie.etat() != Etat::Terminee {
+            return sortie;
+        }
+
+        let joueur = self.joueur_courant();
+        let (des, lancers) = (self.partie.des(), self.partie.lancers());
+        sortie.push(EvenementTable::TourTermine { joueur, des, lancers });
+        self.combinaisons.push((joueur, des));
+
+        // En décharge, les suivants ne lancent pas plus que le meneur.
+        let limite = match self.phase {
+            Phase::Charge => 1,
+            Phase::Decharge if self.combinaisons.len() == 1 => lancers,
+            Phase::Decharge => self.partie.max_lancers(),
+        };
+        if self.combinaisons.len() == self.ordre.len() {
+            self.terminer_manche(&mut sortie);
+        } else {
+            self.nouveau_tour(limite);
+        }
+        sortie
+    }
+
+    fn terminer_manche(&mut self, sortie: &mut Vec<EvenementTable>) {
+        let regles = &self.regles;
+        let mut gagnant = self.combinaisons[0];
+        let mut perdant = self.combinaisons[0];
+        for &(joueur, des) in &self.combinaisons[1..] {
+            if regles.comparer(&des, &gagnant.1).is_gt() {
+                gagnant = (joueur, des);
+            }
+            if regles.comparer(&des, &perdant.1).is_le() {
+                perdant = (joueur, des);
+            }
+        }
+        let valeur = regles.points(&gagnant.1);
+        let (gagnant, perdant) = (gagnant.0, perdant.0);
+
+        let jetons = match self.phase {
+            Phase::Charge => {
+                let jetons = valeur.min(self.pot);
+                self.pot -= jetons;
+                jetons
+            }
+            Phase::Decharge => {
+                let jetons = valeur.min(self.joueurs[gagnant].jetons);
+                self.joueurs[gagnant].jetons -= jetons;
+                jetons
+            }
+        };
+        self.joueurs[perdant].jetons += jetons;
+        sortie.push(EvenementTable::Manche { phase: self.phase, gagnant, perdant, jetons });
+
+        if self.joueurs[perdant].jetons == JETONS {
+            self.perdant = Some(perdant);
+            sortie.push(EvenementTable::FinDePartie { perdant });
+            return;
+        }
+        if self.phase == Phase::Charge && self.pot == 0 {
+            self.phase = Phase::Decharge;
+            sortie.push(EvenementTable::Decharge);
+        }
+
+        // Le perdant mène la manche suivante ; en décharge, seuls jouent ceux qui ont des jetons.
+        let nombre = self.joueurs.len();
+        self.ordre = (0..nombre)
+            .map(|i| (perdant + i) % nombre)
+            .filter(|&j| self.phase == Phase::Charge || self.joueurs[j].jetons > 0)
+            .collect();
+        self.combinaisons.clear();
+        let limite = match self.phase {
+            Phase::Charge => 1,
+            Phase::Decharge => self.max_lancers,
+        };
+        self.nouveau_tour(limite);
+    }
+
+    fn nouveau_tour(&mut self, max_lancers: u32) {
+        self.partie = Partie::avec_regles(max_lancers, Arc::clone(&self.regles))
+            .expect("nombre de lancers déjà vérifié");
+    }
+}
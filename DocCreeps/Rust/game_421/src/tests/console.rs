@@ -0,0 +1,237 @@
+use super::*;
+use game_421::des::DesScriptes;
+use game_421::journal::Coup;
+
+/// Lecture des options --seed et --regles.
+#[test]
+fn options_ligne_de_commande() {
+    let args = |liste: &[&str]| liste.iter().map(|s| s.to_string()).collect::<Vec<_>>().into_iter();
+    assert_eq!(lire_options(args(&[])), Ok(Options::default()));
+    assert_eq!(lire_options(args(&["--seed", "42"])).unwrap().graine, Some(42));
+    assert!(lire_options(args(&["--seed"])).is_err());
+    assert!(lire_options(args(&["--seed", "abc"])).is_err());
+    assert!(lire_options(args(&["--graine"])).is_err());
+    assert_eq!(
+        lire_options(args(&["--regles", "maison.txt", "--seed", "1"])),
+        Ok(Options { graine: Some(1), regles: Some(PathBuf::from("maison.txt")), ..Options::default() })
+    );
+    assert!(lire_options(args(&["--regles"])).is_err());
+    assert_eq!(lire_options(args(&["--joueurs", "3"])).unwrap().joueurs, Some(3));
+    assert!(lire_options(args(&["--joueurs", "1"])).is_err());
+    assert!(lire_options(args(&["--joueurs", "9"])).is_err());
+    let options = lire_options(args(&["--ia", "glouton", "--ia", "esperance"])).unwrap();
+    assert_eq!(options.ordinateurs, vec![Niveau::Glouton, Niveau::Esperance]);
+    assert!(lire_options(args(&["--ia", "expert"])).is_err());
+    assert!(lire_options(args(&["--joueurs", "7", "--ia", "glouton", "--ia", "glouton"])).is_err());
+    let options = lire_options(args(&["--nom", "Alice", "--historique", "h.json"])).unwrap();
+    assert_eq!((options.nom.as_deref(), options.historique), (Some("Alice"), Some(PathBuf::from("h.json"))));
+    assert!(lire_options(args(&["--nom", " "])).is_err());
+    assert!(lire_options(args(&["--tui"])).unwrap().tui);
+    assert!(lire_options(args(&["--tui", "--ia", "glouton"])).is_err());
+    assert_eq!(lire_options(args(&["--journal", "j.json"])).unwrap().journal, Some(PathBuf::from("j.json")));
+    assert!(lire_options(args(&["--journal", "j.json", "--joueurs", "2"])).is_err());
+    assert_eq!(lire_option_historique(args(&[])), Ok(PathBuf::from(FICHIER_HISTORIQUE)));
+    assert_eq!(lire_option_historique(args(&["--historique", "h.json"])), Ok(PathBuf::from("h.json")));
+    assert!(lire_option_historique(args(&["--nom", "Alice"])).is_err());
+}
+
+/// Une session complète pilotée par des réponses et des dés scriptés.
+#[test]
+fn session_scriptee() {
+    // 3 lancers par partie ; partie 1 : relance en gardant le 6, puis arrêt ;
+    // partie 2 : 421 d'emblée.
+    let entree = "0\n3\no\n5\n6\nn\no\nn\n".as_bytes();
+    let mut sortie = Vec::new();
+    let mut des = DesScriptes::new(vec![2, 3, 6, 1, 1, 4, 2, 1]);
+    let journal = Console::new(entree, &mut sortie).session(&mut des, 7).unwrap();
+    let scores = &journal.scores;
+    assert_eq!(scores.len(), 2);
+    assert_eq!((scores[0].lancers, scores[0].lancer_record), (2, 2));
+    assert!(scores[1].est_421() && scores[0].debut <= scores[1].fin);
+    assert_eq!(
+        journal.parties[0].coups,
+        vec![
+            Coup::Lancer { des: [2, 3, 6], points: 1 },
+            Coup::Garder { gardes: [false, false, true] },
+            Coup::Lancer { des: [1, 1, 6], points: 6 },
+            Coup::Arreter,
+        ]
+    );
+    let mut des = DesScriptes::new(vec![2, 3, 6, 1, 1, 4, 2, 1]);
+    assert_eq!(journal.rejouer_avec(&mut des).as_ref(), Ok(scores));
+
+    let texte = String::from_utf8(sortie).unwrap();
+    assert!(texte.contains("Veuillez entrer un nombre entre 1 et 15."));
+    assert!(texte.contains("Aucun dé 5 disponible parmi 2 3 6"));
+    assert!(texte.contains("Votre meilleur score : 6 points avec la combinaison 1 1 6"));
+    assert!(texte.contains("Lancer 2 : 6 points (Fiche de 6)"));
+    assert!(texte.contains("Bravo ! Vous avez obtenu 421 !"));
+    assert!(texte.contains("| Total   | 16     |             | 3        |"));
+    assert!(texte.contains("rejouez cette session avec --seed 7"));
+
+    // Journal sans partie, par exemple rejoué : pas de moyenne sur zéro partie.
+    let mut sortie = Vec::new();
+    Console::new(io::empty(), &mut sortie).afficher_tableau_scores(&[], 7).unwrap();
+    assert_eq!(String::from_utf8(sortie).unwrap(), "\nAucune partie jouée (graine 7).\n");
+}
+
+/// Une entrée qui se termine trop tôt interrompt la session au lieu de boucler.
+#[test]
+fn fin_de_l_entree() {
+    let mut des = DesScriptes::new(vec![]);
+    let erreur = Console::new("".as_bytes(), io::sink()).session(&mut des, 0).unwrap_err();
+    assert_eq!(erreur.kind(), io::ErrorKind::UnexpectedEof);
+}
+
+/// Partie à deux joueurs jusqu'à la fin de la décharge.
+#[test]
+fn session_a_deux_joueurs() {
+    // Noms, lancers de décharge, puis Alice s'arrête après son premier lancer de décharge.
+    let entree = "Alice\n\n3\nn\n".as_bytes();
+    let mut sortie = Vec::new();
+    let mut des = DesScriptes::new(vec![4, 2, 1, 6, 3, 2, 4, 2, 1, 6, 3, 2, 6, 3, 2, 4, 2, 1, 6, 6, 6, 4, 2, 1]);
+    Console::new(entree, &mut sortie).session_table(&mut des, 3, 2, &[]).unwrap();
+
+    let texte = String::from_utf8(sortie).unwrap();
+    assert!(texte.contains("Alice gagne la manche ; Joueur 2 reçoit 10 jeton(s) du pot."));
+    assert!(texte.contains("Le pot est vide : place à la décharge !"));
+    assert!(texte.contains("À Joueur 2 de jouer (10 jeton(s), 1 lancer(s) au plus) :"));
+    assert!(texte.contains("Joueur 2 gagne la manche ; Alice reçoit 10 jeton(s) de Joueur 2."));
+    assert!(texte.contains("Alice a tous les jetons et perd la partie !"));
+}
+
+/// Le joueur peut demander un conseil, et des ordinateurs jouent seuls.
+#[test]
+fn conseil_et_ordinateurs() {
+    let entree = "2\n?\nn\nn\n".as_bytes();
+    let mut sortie = Vec::new();
+    Console::new(entree, &mut sortie).session(&mut DesScriptes::new(vec![4, 6, 2]), 0).unwrap();
+    let texte = String::from_utf8(sortie).unwrap();
+    assert!(texte.contains("Conseil : garder 4 2 et relancer le reste (espérance 2.67 points)"));
+
+    // À la table, seule la dernière combinaison compte : un brelan de 6 ne se relance pas.
+    use game_421::reseau::Message;
+    let messages = [
+        Message::Debut { joueurs: vec!["Alice".to_string()], max_lancers: 2 },
+        Message::Decision { des: [6, 6, 6], lancers_restants: 1 },
+    ];
+    let reception: String = messages.iter().map(|m| serde_json::to_string(m).unwrap() + "\n").collect();
+    let mut sortie = Vec::new();
+    let _ = Console::new("?\nn\n".as_bytes(), &mut sortie).session_reseau(reception.as_bytes(), io::sink(), "Alice");
+    assert!(String::from_utf8(sortie).unwrap().contains("Conseil : s'arrêter (6.00 jetons)"));
+
+    // Deux ordinateurs s'affrontent jusqu'au bout ; seul le nombre de lancers est demandé.
+    let mut sortie = Vec::new();
+    let mut des = game_421::des::DesAleatoires::new(5);
+    let niveaux = [Niveau::Esperance, Niveau::Aleatoire];
+    Console::new("3\n".as_bytes(), &mut sortie).session_table(&mut des, 5, 0, &niveaux).unwrap();
+    let texte = String::from_utf8(sortie).unwrap();
+    assert!(texte.contains("À Ordinateur 2 (aleatoire) de jouer"));
+    assert!(texte.contains("a tous les jetons et perd la partie !"));
+}
+
+/// Lecture des options de la commande simuler.
+#[test]
+fn options_simulation() {
+    let args = |liste: &[&str]| liste.iter().map(|s| s.to_string()).collect::<Vec<_>>().into_iter();
+    assert_eq!(lire_options_simulation(args(&[])), Ok(OptionsSimulation::default()));
+    let liste = ["--parties", "500", "--lancers", "1-15", "--strategie", "glouton", "--csv", "sim.csv"];
+    let options = lire_options_simulation(args(&liste)).unwrap();
+    assert_eq!((options.parties, options.lancers, options.strategie), (500, 1..=15, Niveau::Glouton));
+    assert_eq!(options.csv, Some(PathBuf::from("sim.csv")));
+    assert!(lire_options_simulation(args(&["--parties", "0"])).is_err());
+    assert!(lire_options_simulation(args(&["--lancers", "16"])).is_err());
+    assert!(lire_options_simulation(args(&["--joueurs", "2"])).is_err());
+}
+
+/// Lecture des options des commandes serveur et rejoindre.
+#[test]
+fn options_reseau() {
+    let args = |liste: &[&str]| liste.iter().map(|s| s.to_string()).collect::<Vec<_>>().into_iter();
+    assert_eq!(lire_options_serveur(args(&[])), Ok(OptionsServeur::default()));
+    let options = lire_options_serveur(args(&["--port", "5000", "--joueurs", "4", "--lancers", "2"])).unwrap();
+    assert_eq!((options.port, options.joueurs, options.max_lancers), (5000, 4, 2));
+    assert!(lire_options_serveur(args(&["--joueurs", "9"])).is_err());
+    assert!(lire_options_serveur(args(&["--lancers", "0"])).is_err());
+    assert!(lire_options_serveur(args(&["--port"])).is_err());
+
+    let client = lire_options_client(args(&["salle-de-pause", "--nom", "Alice"]));
+    assert_eq!(client, Ok(("salle-de-pause:4210".to_string(), "Alice".to_string())));
+    assert_eq!(lire_options_client(args(&["10.0.0.2:5000"])).unwrap().0, "10.0.0.2:5000");
+    assert_eq!(lire_options_client(args(&["::1"])).unwrap().0, "[::1]:4210");
+    assert_eq!(lire_options_client(args(&["[::1]:5000"])).unwrap().0, "[::1]:5000");
+    assert_eq!(lire_options_client(args(&["salle-de-pause:5000"])).unwrap().0, "salle-de-pause:5000");
+    assert!(lire_options_client(args(&[])).is_err());
+    assert!(lire_options_client(args(&["a", "b"])).is_err());
+}
+
+/// Le client affiche la partie reçue du serveur et n'envoie que les décisions du joueur.
+#[test]
+fn session_en_reseau() {
+    use game_421::reseau::{Message, Requete};
+    use game_421::table::{EvenementTable, Joueur};
+
+    let messages = [
+        Message::Bienvenue { joueur: 1, attendus: 2 },
+        Message::Debut { joueurs: vec!["Alice".to_string(), "Bob".to_string()], max_lancers: 3 },
+        Message::Tour { joueur: 1, max_lancers: 3 },
+        Message::Evenement {
+            evenement: EvenementTable::Jeu(game_421::partie::Evenement::DesLances {
+                lancer: 1,
+                des: [4, 6, 2],
+                points: 1,
+            }),
+        },
+        Message::Decision { des: [4, 6, 2], lancers_restants: 2 },
+        Message::Erreur { message: "Gardez au plus deux dés pour relancer".to_string() },
+        Message::Decision { des: [4, 6, 2], lancers_restants: 2 },
+        Message::Scores {
+            pot: 0,
+            joueurs: vec![Joueur { nom: "Alice".to_string(), jetons: 21 }, Joueur { nom: "Bob".to_string(), jetons: 0 }],
+        },
+        Message::Evenement { evenement: EvenementTable::FinDePartie { perdant: 0 } },
+    ];
+    let reception: String = messages.iter().map(|m| serde_json::to_string(m).unwrap() + "\n").collect();
+    let mut envoi = Vec::new();
+    let mut sortie = Vec::new();
+    Console::new("o\n4 6 2\nn\n".as_bytes(), &mut sortie)
+        .session_reseau(reception.as_bytes(), &mut envoi, "Bob")
+        .unwrap();
+
+    let requetes: Vec<Requete> =
+        String::from_utf8(envoi).unwrap().lines().map(|l| serde_json::from_str(l).unwrap()).collect();
+    assert_eq!(
+        requetes,
+        vec![
+            Requete::Rejoindre { nom: "Bob".to_string() },
+            Requete::Lancer { gardes: [true; 3] },
+            Requete::Passer,
+        ]
+    );
+    let texte = String::from_utf8(sortie).unwrap();
+    assert!(texte.contains("La partie commence : Alice, Bob (3 lancer(s) au plus)."));
+    assert!(texte.contains("À vous de jouer (3 lancer(s) au plus) :"));
+    assert!(texte.contains("Gardez au plus deux dés pour relancer"));
+    assert!(texte.contains("Pot : 0 | Alice 21 | Bob 0"));
+    assert!(texte.contains("Alice a tous les jetons et perd la partie !"));
+
+    // Connexion coupée avant la fin de la partie.
+    let erreur = Console::new("".as_bytes(), io::sink()).session_reseau("".as_bytes(), io::sink(), "Bob").unwrap_err();
+    assert_eq!(erreur.kind(), io::ErrorKind::UnexpectedEof);
+
+    // Un joueur inconnu désigné par le serveur est une erreur de protocole.
+    for message in [
+        Message::Tour { joueur: 2, max_lancers: 3 },
+        Message::Abandon { joueur: 2 },
+        Message::Evenement { evenement: EvenementTable::FinDePartie { perdant: 2 } },
+    ] {
+        let reception = format!(
+            "{}\n{}\n",
+            serde_json::to_string(&messages[1]).unwrap(),
+            serde_json::to_string(&message).unwrap()
+        );
+        let erreur =
+            Console::new("".as_bytes(), io::sink()).session_reseau(reception.as_bytes(), io::sink(), "Bob").unwrap_err();
+        assert_eq!(erreur.kind(), io::ErrorKind::InvalidData);
+    }
+}
@@ -0,0 +1,525 @@
+use std::sync::Arc;
+
+use crate::des::{DesAleatoires, DesScriptes, SourceDes};
+use crate::partie::*;
+use crate::regles::*;
+
+/// Une même graine produit toujours la même suite de dés.
+#[test]
+fn meme_graine_memes_des() {
+    let lancer = |graine| {
+        let mut source = DesAleatoires::new(graine);
+        (0..30).map(|_| source.lancer_de()).collect::<Vec<u8>>()
+    };
+    assert_eq!(lancer(421), lancer(421));
+    assert_ne!(lancer(421), lancer(422));
+    assert!(lancer(7).iter().all(|d| (1..=6).contains(d)));
+}
+
+/// Jetons de la table traditionnelle, quel que soit l'ordre des dés.
+#[test]
+fn points_des_combinaisons() {
+    assert_eq!(calculer_points(&[4, 2, 1]), 10);
+    assert_eq!(calculer_points(&[1, 4, 2]), 10);
+    assert_eq!(calculer_points(&[1, 1, 1]), 7);
+    assert_eq!(calculer_points(&[6, 1, 1]), 6);
+    assert_eq!(calculer_points(&[1, 3, 1]), 3);
+    assert_eq!(calculer_points(&[5, 5, 5]), 5);
+    assert_eq!(calculer_points(&[2, 2, 2]), 2);
+    assert_eq!(calculer_points(&[5, 3, 4]), 2);
+    assert_eq!(calculer_points(&[2, 1, 2]), 2);
+    assert_eq!(calculer_points(&[3, 2, 6]), 1);
+}
+
+/// Classement traditionnel : 421 > Mac > fiches et brelans > suites > ordinaires > nénette.
+#[test]
+fn classement_traditionnel() {
+    let regles = Regles::traditionnelles();
+    let ordre = [
+        [4, 2, 1], [1, 1, 1], [6, 1, 1], [6, 6, 6], [5, 1, 1], [4, 4, 4], [2, 2, 2],
+        [6, 5, 4], [3, 2, 1], [6, 6, 5], [6, 4, 2], [5, 3, 1], [2, 2, 1],
+    ];
+    for paire in ordre.windows(2) {
+        assert!(regles.force(&paire[0]) > regles.force(&paire[1]), "{:?}", paire);
+    }
+    // L'ordre des dés ne compte pas.
+    assert_eq!(regles.comparer(&[1, 2, 4], &[4, 2, 1]), std::cmp::Ordering::Equal);
+    assert_eq!(regles.nom(&[3, 4, 5]), "Suite 345");
+    assert_eq!(regles.nom(&[6, 3, 2]), "Ordinaire");
+}
+
+/// Une table maison se relit à l'identique et les erreurs sont localisées.
+#[test]
+fn table_de_regles_maison() {
+    let regles = Regles::traditionnelles();
+    assert_eq!(regles.to_string().parse::<Regles>(), Ok(regles));
+
+    let maison: Regles = "# variante\n421 8 Le roi\n666 7\n".parse().unwrap();
+    assert_eq!(maison.points(&[2, 4, 1]), 8);
+    assert_eq!(maison.nom(&[6, 6, 6]), "666");
+    assert_eq!(maison.points(&[1, 1, 1]), 1);
+    assert!(maison.force(&[1, 1, 1]) < maison.force(&[6, 6, 6]));
+
+    assert_eq!("421 dix".parse::<Regles>().unwrap_err(), "ligne 1 : nombre de jetons invalide : 'dix'");
+    assert!("427 3".parse::<Regles>().is_err());
+    assert!("421 3\n124 3".parse::<Regles>().is_err());
+    assert!("* 1\n* 2".parse::<Regles>().is_err());
+
+    // Avec une table maison, la meilleure combinaison suit son classement.
+    let regles = Arc::new("221 5\n* 1".parse::<Regles>().unwrap());
+    let mut des = DesScriptes::new(vec![2, 2, 1, 6, 6, 6]);
+    let mut partie = Partie::avec_regles(2, regles).unwrap();
+    partie.lancer(&mut des).unwrap();
+    partie.lancer(&mut des).unwrap();
+    assert_eq!(partie.resultat(), Some(Resultat { points: 5, des: [2, 2, 1], lancers: 2, obtenu_au: 1 }));
+}
+
+/// Un 421 termine la partie ; plus aucune action n'est possible ensuite.
+#[test]
+fn partie_terminee_par_421() {
+    let mut des = DesScriptes::new(vec![6, 1, 1, 4, 2, 1]);
+    let mut partie = Partie::new(5).unwrap();
+    assert_eq!(partie.etat(), Etat::Nouvelle);
+    assert!(partie.arreter().is_err());
+
+    partie.lancer(&mut des).unwrap();
+    assert_eq!(partie.etat(), Etat::Decision);
+    assert_eq!(partie.lancers_restants(), 4);
+
+    let resultat = Resultat { points: 10, des: [4, 2, 1], lancers: 2, obtenu_au: 2 };
+    assert_eq!(
+        partie.lancer(&mut des).unwrap(),
+        vec![
+            Evenement::DesLances { lancer: 2, des: [4, 2, 1], points: 10 },
+            Evenement::Quatre21,
+            Evenement::PartieTerminee(resultat),
+        ]
+    );
+    assert_eq!(partie.resultat(), Some(resultat));
+    assert!(partie.lancer(&mut des).is_err());
+    assert!(partie.arreter().is_err());
+}
+
+/// En s'arrêtant, le joueur garde sa meilleure combinaison, pas la dernière.
+#[test]
+fn arret_garde_le_meilleur_lancer() {
+    let mut des = DesScriptes::new(vec![5, 5, 5, 2, 3, 6]);
+    let mut partie = Partie::new(3).unwrap();
+    partie.lancer(&mut des).unwrap();
+    partie.lancer(&mut des).unwrap();
+    assert_eq!(partie.resultat(), None);
+
+    let resultat = Resultat { points: 5, des: [5, 5, 5], lancers: 2, obtenu_au: 1 };
+    assert_eq!(partie.arreter(), Ok(Evenement::PartieTerminee(resultat)));
+    assert_eq!(partie.etat(), Etat::Terminee);
+    let score = Score::new(1, &resultat, 100, 160);
+    assert_eq!((score.combinaison.as_str(), score.lancer_record), ("5 5 5", 1));
+    assert!(!score.est_421());
+}
+
+/// La partie s'arrête d'elle-même une fois tous les lancers joués.
+#[test]
+fn partie_terminee_apres_le_dernier_lancer() {
+    let mut des = DesScriptes::new(vec![2, 3, 6, 6, 6, 6]);
+    let mut partie = Partie::new(2).unwrap();
+    partie.lancer(&mut des).unwrap();
+    let evenements = partie.lancer(&mut des).unwrap();
+    assert_eq!(evenements.len(), 2);
+    assert_eq!(partie.resultat(), Some(Resultat { points: 6, des: [6, 6, 6], lancers: 2, obtenu_au: 2 }));
+
+    assert!(Partie::new(0).is_err());
+    assert!(Partie::new(MAX_LANCERS + 1).is_err());
+}
+
+/// Les dés gardés ne sont pas relancés, et le choix ne vaut que pour un lancer.
+#[test]
+fn relance_selective() {
+    assert_eq!(choisir_gardes(&[4, 4, 2], &[4, 2]), Ok([true, false, true]));
+    assert_eq!(choisir_gardes(&[4, 4, 2], &[]), Ok([false; 3]));
+    assert!(choisir_gardes(&[4, 4, 2], &[2, 2]).is_err());
+
+    let mut des = DesScriptes::new(vec![4, 6, 6, 2, 1]);
+    let mut partie = Partie::new(5).unwrap();
+    assert!(partie.garder([true, false, false]).is_err());
+    partie.lancer(&mut des).unwrap();
+    assert!(partie.garder([true; 3]).is_err());
+
+    partie.garder([true, false, false]).unwrap();
+    partie.lancer(&mut des).unwrap();
+    assert_eq!(partie.des(), [4, 2, 1]);
+    assert!(est_421(&partie.resultat().unwrap().des));
+    assert!(partie.garder([false; 3]).is_err());
+
+    let mut partie = Partie::new(5).unwrap();
+    let mut des = DesScriptes::new(vec![1, 2, 3, 4, 5, 6]);
+    partie.lancer(&mut des).unwrap();
+    partie.garder([false, true, false]).unwrap();
+    partie.lancer(&mut des).unwrap();
+    assert_eq!(partie.des(), [4, 2, 5]);
+    assert_eq!(partie.gardes(), [false; 3]);
+}
+
+/// Charge puis décharge à deux joueurs, jusqu'à ce que l'un ait tous les jetons.
+#[test]
+fn partie_a_deux_joueurs() {
+    use crate::table::*;
+
+    let noms = vec!["Alice".to_string(), "Bob".to_string()];
+    assert!(Table::new(noms[..1].to_vec(), 3, Arc::new(Regles::traditionnelles())).is_err());
+    let mut table = Table::new(noms, 3, Arc::new(Regles::traditionnelles())).unwrap();
+    let mut des = DesScriptes::new(vec![
+        4, 2, 1, 6, 3, 2, // charge : Bob prend 10 jetons
+        4, 2, 1, 6, 3, 2, // Bob mène et gagne : Alice prend 10 jetons
+        6, 3, 2, 4, 2, 1, // Alice mène et perd : elle prend le dernier jeton
+        6, 6, 6, 4, 2, 1, // décharge : Bob donne 10 jetons à Alice
+    ]);
+
+    let evenements = table.lancer(&mut des).unwrap();
+    assert_eq!(evenements.last(), Some(&EvenementTable::TourTermine { joueur: 0, des: [4, 2, 1], lancers: 1 }));
+    let evenements = table.lancer(&mut des).unwrap();
+    assert_eq!(
+        evenements.last(),
+        Some(&EvenementTable::Manche { phase: Phase::Charge, gagnant: 0, perdant: 1, jetons: 10 })
+    );
+    assert_eq!((table.pot(), table.joueur_courant()), (11, 1));
+
+    for _ in 0..3 {
+        table.lancer(&mut des).unwrap();
+    }
+    let evenements = table.lancer(&mut des).unwrap();
+    assert!(evenements.contains(&EvenementTable::Decharge));
+    assert_eq!((table.pot(), table.joueurs()[0].jetons, table.joueurs()[1].jetons), (0, 11, 10));
+    assert_eq!(table.phase(), Phase::Decharge);
+
+    // Alice mène et s'arrête après un lancer : Bob n'a droit qu'à un lancer.
+    assert_eq!(table.joueur_courant(), 0);
+    table.lancer(&mut des).unwrap();
+    table.arreter().unwrap();
+    assert_eq!((table.joueur_courant(), table.partie().max_lancers()), (1, 1));
+    let evenements = table.lancer(&mut des).unwrap();
+    assert_eq!(evenements.last(), Some(&EvenementTable::FinDePartie { perdant: 0 }));
+    assert_eq!(table.joueurs()[0].jetons, JETONS);
+    assert!(table.terminee());
+    assert!(table.lancer(&mut des).is_err());
+}
+
+/// Le solveur s'arrête sur une bonne combinaison et garde les bons dés.
+#[test]
+fn solveur_esperance() {
+    use crate::ia::*;
+
+    let regles = Arc::new(Regles::traditionnelles());
+    let mut solveur = Solveur::new(Arc::clone(&regles), Comptage::Derniere);
+    assert_eq!(solveur.conseil(&[6, 6, 6], &[6, 6, 6], 2).gardes, None);
+    assert_eq!(solveur.conseil(&[6, 3, 2], &[6, 3, 2], 0), Conseil { gardes: None, esperance: 1.0 });
+
+    // 4 et 2 gardés, une relance : 421 une fois sur six, suite 432 une fois sur six.
+    let conseil = solveur.conseil(&[4, 6, 2], &[4, 6, 2], 1);
+    assert_eq!(conseil.gardes, Some([true, false, true]));
+    assert!((conseil.esperance - 16.0 / 6.0).abs() < 1e-9);
+
+    // Plus il reste de lancers, plus l'espérance est grande.
+    let esperances: Vec<f64> = (0..4).map(|r| solveur.valeur(&[6, 3, 2], &[6, 3, 2], r)).collect();
+    assert!(esperances.windows(2).all(|e| e[0] < e[1]), "{:?}", esperances);
+
+    // En solo, la meilleure combinaison reste acquise : relancer un 666 ne
+    // coûte rien et peut donner mieux (Mac, 421).
+    let mut solo = Solveur::new(Arc::clone(&regles), Comptage::Meilleure);
+    let conseil = solo.conseil(&[6, 6, 6], &[6, 6, 6], 2);
+    assert!(conseil.gardes.is_some() && conseil.esperance > 6.0, "{:?}", conseil);
+    // Dés en main plus faibles que l'acquis : l'arrêt vaut l'acquis.
+    assert_eq!(solo.conseil(&[6, 3, 2], &[4, 2, 1], 0).esperance, 10.0);
+    assert!(solo.valeur(&[6, 3, 2], &[6, 6, 6], 1) > solveur.valeur(&[6, 3, 2], &[6, 3, 2], 1));
+
+    // La partie solo fournit elle-même sa meilleure combinaison.
+    let mut partie = Partie::new(3).unwrap();
+    let mut des = DesScriptes::new(vec![6, 6, 6, 5, 3, 2]);
+    partie.lancer(&mut des).unwrap();
+    partie.garder([false; 3]).unwrap();
+    partie.lancer(&mut des).unwrap();
+    let conseil = solo.conseil_partie(&partie);
+    assert!(conseil.gardes.is_some() && conseil.esperance > 6.0, "{:?}", conseil);
+
+    let mut partie = Partie::new(3).unwrap();
+    partie.lancer(&mut DesScriptes::new(vec![1, 1, 5])).unwrap();
+    for niveau in Niveau::TOUS {
+        assert_eq!(niveau.to_string().parse(), Ok(niveau));
+        let mut ordinateur = Ordinateur::new(niveau, Arc::clone(&regles), Comptage::Meilleure, 1);
+        if let Some(gardes) = ordinateur.decider(&partie) {
+            assert!(!gardes.iter().all(|&g| g));
+        }
+    }
+    assert!("expert".parse::<Niveau>().is_err());
+}
+
+/// La simulation est reproductible et ses fréquences sont cohérentes.
+#[test]
+fn simulation_monte_carlo() {
+    use crate::ia::Niveau;
+    use crate::simulation::*;
+
+    assert_eq!(parse_lancers("3"), Ok(3..=3));
+    assert_eq!(parse_lancers("1-15"), Ok(1..=15));
+    assert!(parse_lancers("0").is_err());
+    assert!(parse_lancers("5-2").is_err());
+
+    let config = ConfigSimulation {
+        parties: 20_000,
+        lancers: 1..=3,
+        strategie: Niveau::Esperance,
+        graine: 421,
+        regles: Arc::new(Regles::traditionnelles()),
+    };
+    let rapports = simuler(&config);
+    assert_eq!(rapports, simuler(&config));
+    assert_eq!(rapports.len(), 3);
+
+    // Un seul lancer : le 421 sort une fois sur 36 (6 ordres sur 216).
+    let un_lancer = &rapports[0];
+    assert!((un_lancer.probabilite("Quatre-cent-vingt-et-un") - 1.0 / 36.0).abs() < 0.005);
+    assert_eq!(un_lancer.combinaisons.values().sum::<u64>(), config.parties);
+    // Plus de lancers rapportent plus, et le 421 peut sortir au dernier lancer.
+    assert!(rapports.windows(2).all(|r| r[0].score_moyen() < r[1].score_moyen()));
+    assert!(rapports[2].parties_421[2] > 0);
+
+    let mut csv = Vec::new();
+    ecrire_csv(&rapports, &mut csv).unwrap();
+    let csv = String::from_utf8(csv).unwrap();
+    assert!(csv.starts_with("lancers,mesure,cle,valeur\n1,score_moyen,,"));
+    assert!(csv.contains("\n3,lancers_421,aucun,"));
+    assert!(histogramme(un_lancer, 20).contains("  Ordinaire "));
+}
+
+/// L'historique se relit à l'identique et le classement cumule les sessions.
+#[test]
+fn historique_et_classement() {
+    use crate::historique::*;
+
+    assert_eq!(formater_date(0), "1970-01-01 00:00");
+    assert_eq!(formater_date(951_782_400), "2000-02-29 00:00");
+    assert_eq!(formater_date(1_709_316_300), "2024-03-01 18:05");
+
+    let score = |partie, points, combinaison: &str| Score {
+        partie,
+        points,
+        combinaison: combinaison.to_string(),
+        lancers: 3,
+        lancer_record: 2,
+        debut: 1_709_316_000,
+        fin: 1_709_316_300,
+    };
+    let mut historique = Historique::default();
+    let session = |graine, scores| Session { graine, scores, points_ordinaires: 1 };
+    historique.ajouter("Alice", session(1, vec![score(1, 10, "4 2 1"), score(2, 10, "1 2 4")]));
+    historique.ajouter("Alice", session(2, vec![score(1, 1, "6 3 2"), score(2, 6, "6 1 1")]));
+    historique.ajouter("Bob", session(3, vec![score(1, 7, "1 1 1")]));
+    historique.ajouter("Carole", session(4, vec![]));
+    // Une partie ordinaire interrompt la série, une combinaison qui rapporte
+    // davantage la prolonge, selon la table de règles de chaque session.
+    let nenettes = vec![score(1, 2, "2 2 1"), score(2, 2, "1 2 2"), score(3, 1, "6 3 2"), score(4, 2, "2 1 2")];
+    historique.ajouter("Denis", session(5, nenettes));
+    let maison = vec![score(1, 2, "6 3 2"), score(2, 3, "2 2 1")];
+    historique.ajouter("Denis", Session { graine: 6, scores: maison, points_ordinaires: 2 });
+    assert_eq!(historique.joueurs.len(), 3);
+
+    let classement = historique.classement();
+    assert_eq!(classement[0].joueur, "Alice");
+    assert_eq!((classement[0].parties, classement[0].meilleur.partie), (4, 1));
+    assert!((classement[0].moyenne - 6.75).abs() < 1e-9);
+    assert!((classement[0].frequence_421 - 0.5).abs() < 1e-9);
+    assert_eq!((classement[0].serie_421, classement[0].serie_gagnante), (2, 2));
+    assert_eq!(classement[1].joueur, "Bob");
+    let denis = classement.iter().find(|c| c.joueur == "Denis").unwrap();
+    assert_eq!((denis.serie_421, denis.serie_gagnante), (0, 2));
+    // Historique enregistré avant le champ : table traditionnelle.
+    let ancienne: Session = serde_json::from_str(r#"{"graine": 1, "scores": []}"#).unwrap();
+    assert_eq!(ancienne.points_ordinaires, 1);
+    let tableau = formater_classement(&classement);
+    assert!(tableau.contains("| 1  | Alice "));
+    assert!(tableau.contains("| 2 / 2                |"));
+    assert!(tableau.contains("10 pts (4 2 1, lancer 2) 2024-03-01"));
+
+    let dossier = std::env::temp_dir().join(format!("game_421_historique_{}", std::process::id()));
+    std::fs::create_dir_all(&dossier).unwrap();
+    let chemin = dossier.join("scores.json");
+    assert_eq!(Historique::charger(&chemin), Ok(Historique::default()));
+    historique.sauvegarder(&chemin).unwrap();
+    assert_eq!(Historique::charger(&chemin), Ok(historique));
+    std::fs::write(&chemin, "pas du json").unwrap();
+    assert!(Historique::charger(&chemin).is_err());
+    std::fs::remove_dir_all(&dossier).unwrap();
+}
+
+/// Partie à deux en réseau sur localhost : le serveur lance les dés et
+/// refuse les requêtes hors tour ou invalides.
+#[test]
+fn partie_en_reseau() {
+    use crate::reseau::*;
+    use crate::table::{EvenementTable, Phase};
+    use std::io::BufReader;
+    use std::net::{TcpListener, TcpStream};
+    use std::sync::mpsc;
+    use std::thread;
+
+    // Chaque client répond aux messages reçus, jusqu'à la fermeture de la connexion.
+    // Les joueurs sont placés dans l'ordre des connexions.
+    fn client(
+        adresse: std::net::SocketAddr,
+        nom: &str,
+        mut reagir: impl FnMut(&Message) -> Option<Requete> + Send + 'static,
+    ) -> thread::JoinHandle<Vec<Message>> {
+        let nom = nom.to_string();
+        let mut flux = TcpStream::connect(adresse).unwrap();
+        thread::spawn(move || {
+            let mut lecture = BufReader::new(flux.try_clone().unwrap());
+            envoyer(&mut flux, &Requete::Rejoindre { nom }).unwrap();
+            let mut recus = Vec::new();
+            while let Some(message) = recevoir::<_, Message>(&mut lecture).unwrap() {
+                if let Some(requete) = reagir(&message) {
+                    envoyer(&mut flux, &requete).unwrap();
+                }
+                recus.push(message);
+            }
+            recus
+        })
+    }
+
+    // Toutes les combinaisons valent 11 : deux manches de charge, puis une de décharge.
+    let regles = Arc::new("* 11".parse::<Regles>().unwrap());
+    let ecoute = TcpListener::bind("127.0.0.1:0").unwrap();
+    let adresse = ecoute.local_addr().unwrap();
+    let serveur = thread::spawn(move || {
+        let mut des = DesScriptes::new(vec![6, 5, 4, 3, 2, 1, 6, 6, 6, 2, 2, 1, 5, 3, 1, 6, 6, 2, 2, 2]);
+        let mut serveur = Serveur::accueillir(&ecoute, 2, 2, regles, DELAI_PRESENTATION).unwrap();
+        serveur.jouer(&mut des).unwrap()
+    });
+
+    // Bob tente de passer pendant le tour d'Alice, qui attend son refus pour jouer.
+    let (refus, refus_recu) = mpsc::channel();
+    let mut essais = 0;
+    let alice = client(adresse, "Alice", move |message| match message {
+        Message::Decision { .. } => {
+            essais += 1;
+            match essais {
+                1 => {
+                    refus_recu.recv().unwrap();
+                    Some(Requete::Lancer { gardes: [true; 3] })
+                }
+                _ => Some(Requete::Lancer { gardes: [true, false, false] }),
+            }
+        }
+        _ => None,
+    });
+    let mut attend_scores = false;
+    let bob = client(adresse, "Bob", move |message| match message {
+        Message::Evenement { evenement: EvenementTable::Decharge } => Some(Requete::Passer),
+        Message::Erreur { .. } => {
+            refus.send(()).unwrap();
+            None
+        }
+        Message::Decision { .. } => {
+            attend_scores = true;
+            Some(Requete::Scores)
+        }
+        Message::Scores { .. } if attend_scores => {
+            attend_scores = false;
+            Some(Requete::Passer)
+        }
+        _ => None,
+    });
+
+    assert_eq!(serveur.join().unwrap(), 1);
+    let (alice, bob) = (alice.join().unwrap(), bob.join().unwrap());
+    assert_eq!(alice[0], Message::Bienvenue { joueur: 0, attendus: 2 });
+    assert_eq!(alice[1], Message::Debut { joueurs: vec!["Alice".to_string(), "Bob".to_string()], max_lancers: 2 });
+    assert!(alice.contains(&Message::Erreur { message: "Gardez au plus deux dés pour relancer".to_string() }));
+    assert!(bob.contains(&Message::Erreur { message: "Ce n'est pas votre tour : à Alice de jouer".to_string() }));
+    // Bob ne reçoit jamais les décisions d'Alice, mais voit ses lancers.
+    assert_eq!(bob.iter().filter(|m| matches!(m, Message::Decision { .. })).count(), 1);
+    let manche = EvenementTable::Manche { phase: Phase::Decharge, gagnant: 0, perdant: 1, jetons: 10 };
+    for recus in [&alice, &bob] {
+        assert!(recus.contains(&Message::Evenement { evenement: manche }));
+        assert_eq!(recus.last(), Some(&Message::Evenement { evenement: EvenementTable::FinDePartie { perdant: 1 } }));
+    }
+}
+
+/// Un client qui ne se présente pas est déconnecté sans bloquer les suivants.
+#[test]
+fn reseau_client_muet() {
+    use crate::reseau::*;
+    use std::io::BufReader;
+    use std::net::{TcpListener, TcpStream};
+    use std::time::Duration;
+
+    let ecoute = TcpListener::bind("127.0.0.1:0").unwrap();
+    let adresse = ecoute.local_addr().unwrap();
+    let muet = TcpStream::connect(adresse).unwrap();
+    let mut joueurs = Vec::new();
+    for nom in ["Alice", "Bob"] {
+        let mut flux = TcpStream::connect(adresse).unwrap();
+        envoyer(&mut flux, &Requete::Rejoindre { nom: nom.to_string() }).unwrap();
+        joueurs.push(flux);
+    }
+
+    let regles = Arc::new(Regles::traditionnelles());
+    let serveur = Serveur::accueillir(&ecoute, 2, 3, regles, Duration::from_millis(100)).unwrap();
+    assert_eq!(serveur.table().joueurs()[0].nom, "Alice");
+    assert_eq!(recevoir::<_, Message>(&mut BufReader::new(muet)).unwrap(), None);
+    let bienvenue = recevoir::<_, Message>(&mut BufReader::new(&joueurs[1])).unwrap();
+    assert_eq!(bienvenue, Some(Message::Bienvenue { joueur: 1, attendus: 2 }));
+}
+
+/// Un journal se rejoue à l'identique depuis sa graine ; tout écart est signalé.
+#[test]
+fn journal_et_rejeu() {
+    use crate::journal::*;
+
+    // Session de deux parties : on garde le meilleur dé puis on s'arrête.
+    let regles = Regles::traditionnelles();
+    let mut journal = Journal::new(2024, &regles);
+    let mut des = DesAleatoires::new(2024);
+    for numero in 1..=2 {
+        journal.nouvelle_partie(3);
+        let mut partie = Partie::new(3).unwrap();
+        journal.noter_evenements(&partie.lancer(&mut des).unwrap());
+        if partie.etat() == Etat::Decision {
+            let gardes = choisir_gardes(&partie.des(), &[trier_des(&partie.des())[0]]).unwrap();
+            partie.garder(gardes).unwrap();
+            journal.noter(Coup::Garder { gardes });
+            journal.noter_evenements(&partie.lancer(&mut des).unwrap());
+        }
+        if partie.etat() == Etat::Decision {
+            partie.arreter().unwrap();
+            journal.noter(Coup::Arreter);
+        }
+        journal.terminer_partie(Score::new(numero, &partie.resultat().unwrap(), 100, 160));
+    }
+    assert_eq!(journal.rejouer(), Ok(journal.scores.clone()));
+
+    let dossier = std::env::temp_dir().join(format!("game_421_journal_{}", std::process::id()));
+    std::fs::create_dir_all(&dossier).unwrap();
+    let chemin = dossier.join("session.json");
+    journal.sauvegarder(&chemin).unwrap();
+    assert_eq!(Journal::charger(&chemin), Ok(journal.clone()));
+    std::fs::remove_dir_all(&dossier).unwrap();
+
+    // Points d'un lancer falsifiés : le calcul des points ne correspond plus.
+    let mut falsifie = journal.clone();
+    let Coup::Lancer { des, points } = falsifie.parties[0].coups[0] else { panic!("premier coup : un lancer") };
+    falsifie.parties[0].coups[0] = Coup::Lancer { des, points: points + 1 };
+    let erreur = falsifie.rejouer().unwrap_err();
+    assert!(erreur.starts_with("Partie 1 : coup 1 : lancer "), "{}", erreur);
+
+    let mut falsifie = journal.clone();
+    falsifie.graine += 1;
+    assert!(falsifie.rejouer().is_err());
+    let mut falsifie = journal.clone();
+    falsifie.scores[1].points += 1;
+    assert!(falsifie.rejouer().unwrap_err().starts_with("Partie 2 : score de "));
+    let mut falsifie = journal;
+    falsifie.scores.pop();
+    assert!(falsifie.rejouer().is_err());
+
+    // Journal d'une session quittée avant la première partie.
+    assert_eq!(Journal::new(2024, &regles).rejouer(), Ok(Vec::new()));
+}
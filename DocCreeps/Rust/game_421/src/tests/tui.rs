@@ -0,0 +1,93 @@
+use super::*;
+use crossterm::event::KeyCode;
+use game_421::des::DesScriptes;
+
+/// Texte de la ligne `y` du tampon.
+fn line(buf: &Buffer, y: u16) -> String {
+    (0..buf.area.width).map(|x| buf[(x, y)].symbol()).collect()
+}
+
+fn ecran(jeu: &Jeu421) -> String {
+    let mut buf = Buffer::empty(Rect::new(0, 0, 100, 24));
+    jeu.render(buf.area, &mut buf);
+    (0..buf.area.height).map(|y| line(&buf, y)).collect::<Vec<_>>().join("\n")
+}
+
+fn touche(jeu: &mut Jeu421, code: KeyCode) {
+    jeu.handle_key_event(code.into());
+    while jeu.animation > 0 {
+        jeu.tick();
+    }
+}
+
+#[test]
+fn faces_des_des() {
+    assert_eq!(face(5)[1], "| o   o |");
+    assert_eq!(face(5)[2], "|   o   |");
+    assert_eq!(face(1)[1], "|       |");
+}
+
+/// Réglage, lancer animé, dés gardés avec 1-3, puis tableau des scores.
+#[test]
+fn partie_au_clavier() {
+    let mut source = DesScriptes::new(vec![6, 4, 3, 2, 1, 5, 5, 3]);
+    let mut jeu = Jeu421::new(&mut source, Regles::traditionnelles(), 7);
+    assert!(ecran(&jeu).contains("Lancers par partie : < 3 >"));
+    touche(&mut jeu, KeyCode::Right);
+    touche(&mut jeu, KeyCode::Left);
+    touche(&mut jeu, KeyCode::Left);
+    assert_eq!(jeu.max_lancers, 2);
+
+    // Premier lancer : 6 4 3. Les dés roulent pendant l'animation.
+    jeu.handle_key_event(KeyCode::Enter.into());
+    assert_eq!(jeu.animation, IMAGES_ANIMATION);
+    assert!(!ecran(&jeu).contains("Lancer 1 : 6 4 3"));
+    while jeu.animation > 0 {
+        jeu.tick();
+    }
+    let texte = ecran(&jeu);
+    assert!(texte.contains("Lancer 1 : 6 4 3 - 1 point(s)"), "{}", texte);
+    assert!(texte.contains("Lancer 1/2"));
+
+    // Garde le 4, relance : 4 2 1.
+    touche(&mut jeu, KeyCode::Char('2'));
+    touche(&mut jeu, KeyCode::Char('1'));
+    touche(&mut jeu, KeyCode::Char('1'));
+    assert_eq!(jeu.gardes, [false, true, false]);
+    assert!(ecran(&jeu).contains("[2] gardé"));
+    touche(&mut jeu, KeyCode::Char(' '));
+    assert_eq!(jeu.partie.as_ref().unwrap().des(), [2, 4, 1]);
+    assert!(ecran(&jeu).contains("Bravo ! Vous avez obtenu 421 !"));
+    assert_eq!(jeu.enregistrement.scores.len(), 1);
+    assert_eq!(jeu.enregistrement.scores[0].points, 10);
+
+    // Seconde partie, arrêtée par q : elle compte aussi.
+    touche(&mut jeu, KeyCode::Char('n'));
+    touche(&mut jeu, KeyCode::Char('q'));
+    assert_eq!(jeu.ecran, Ecran::Bilan);
+    assert_eq!(jeu.enregistrement.scores.len(), 2);
+    let texte = ecran(&jeu);
+    assert!(texte.contains("Tableau des scores"));
+    assert!(texte.contains("Nombre de parties : 2"));
+    assert!(!texte.contains("+---"));
+    let mut des = DesScriptes::new(vec![6, 4, 3, 2, 1, 5, 5, 3]);
+    assert_eq!(jeu.enregistrement.rejouer_avec(&mut des), Ok(jeu.enregistrement.scores.clone()));
+    touche(&mut jeu, KeyCode::Char('q'));
+    assert!(jeu.exit);
+}
+
+/// On ne garde pas les trois dés, et le conseil coche les dés à garder.
+#[test]
+fn gardes_et_conseil() {
+    let mut source = DesScriptes::new(vec![4, 6, 2]);
+    let mut jeu = Jeu421::new(&mut source, Regles::traditionnelles(), 0);
+    touche(&mut jeu, KeyCode::Enter);
+    touche(&mut jeu, KeyCode::Char('?'));
+    assert_eq!(jeu.gardes, [true, false, true]);
+    assert!(jeu.message.starts_with("Conseil : garder 1 et 3"));
+
+    touche(&mut jeu, KeyCode::Char('2'));
+    touche(&mut jeu, KeyCode::Char(' '));
+    assert!(!jeu.message.is_empty());
+    assert_eq!(jeu.partie.as_ref().unwrap().lancers(), 1);
+}
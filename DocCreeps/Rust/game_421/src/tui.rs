@@ -0,0 +1,515 @@
+use std::io;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use crossterm::{
+    event::{self, Event, KeyCode, KeyEvent, KeyEventKind},
+    execute,
+    terminal::{self, EnterAlternateScreen, LeaveAlternateScreen},
+};
+use game_421::des::SourceDes;
+use game_421::historique::maintenant;
+use game_421::ia::{Comptage, Solveur};
+use game_421::journal::{Coup, Journal};
+use game_421::partie::{Etat, Evenement, Partie, Score, MAX_LANCERS};
+use game_421::regles::Regles;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use ratatui::{
+    buffer::Buffer,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Style, Stylize},
+    symbols::border,
+    text::Line,
+    widgets::{Block, Paragraph, Row, Table, Widget},
+};
+
+/// Durée d'une image de l'animation.
+const TICK: Duration = Duration::from_millis(50);
+
+/// Images pendant lesquelles les dés roulent après un lancer.
+const IMAGES_ANIMATION: u8 = 8;
+
+/// Lignes du journal de la partie gardées à l'écran.
+const JOURNAL_MAX: usize = 8;
+
+// Fonction d'initialisation et de restauration du terminal
+fn init() -> io::Result<ratatui::Terminal<ratatui::backend::CrosstermBackend<io::Stdout>>> {
+    execute!(io::stdout(), EnterAlternateScreen)?;
+    terminal::enable_raw_mode()?;
+    ratatui::Terminal::new(ratatui::backend::CrosstermBackend::new(io::stdout()))
+}
+
+fn restore() -> io::Result<()> {
+    execute!(io::stdout(), LeaveAlternateScreen)?;
+    terminal::disable_raw_mode()?;
+    Ok(())
+}
+
+/// Lance l'interface plein écran jusqu'à ce que le joueur quitte, et renvoie
+/// le journal de la session, scores compris.
+pub fn run(source: &mut dyn SourceDes, regles: Regles, graine: u64) -> io::Result<Journal> {
+    let mut terminal = init()?;
+    let mut jeu = Jeu421::new(source, regles, graine);
+    let app_result = jeu.run(&mut terminal);
+    restore()?;
+    app_result.map(|()| jeu.enregistrement)
+}
+
+/// Dessin d'une face de dé : 5 lignes de 9 caractères.
+fn face(valeur: u8) -> [&'static str; 5] {
+    let (haut, milieu, bas) = match valeur {
+        1 => ("|       |", "|   o   |", "|       |"),
+        2 => ("| o     |", "|       |", "|     o |"),
+        3 => ("| o     |", "|   o   |", "|     o |"),
+        4 => ("| o   o |", "|       |", "| o   o |"),
+        5 => ("| o   o |", "|   o   |", "| o   o |"),
+        6 => ("| o   o |", "| o   o |", "| o   o |"),
+        _ => ("|       |", "|   ?   |", "|       |"),
+    };
+    ["+-------+", haut, milieu, bas, "+-------+"]
+}
+
+/// Écran affiché.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Ecran {
+    /// Choix du nombre de lancers par partie.
+    Reglage,
+    Jeu,
+    /// Tableau des scores de la session.
+    Bilan,
+}
+
+/// État de l'interface : une session solo, partie après partie.
+struct Jeu421<'a> {
+    source: &'a mut dyn SourceDes,
+    regles: Arc<Regles>,
+    solveur: Solveur,
+    graine: u64,
+    ecran: Ecran,
+    max_lancers: u32,
+    partie: Option<Partie>,
+    debut_partie: u64,
+    /// Dés que le joueur veut garder au prochain lancer.
+    gardes: [bool; 3],
+    /// Images d'animation restantes ; les dés non gardés affichent `faces`.
+    animation: u8,
+    gardes_animation: [bool; 3],
+    faces: [u8; 3],
+    /// Hasard de l'animation seulement : il ne touche pas aux dés de la partie.
+    rng: StdRng,
+    journal: Vec<String>,
+    message: String,
+    /// Coups et scores de la session, pour l'historique et le rejeu.
+    enregistrement: Journal,
+    exit: bool,
+}
+
+impl<'a> Jeu421<'a> {
+    fn new(source: &'a mut dyn SourceDes, regles: Regles, graine: u64) -> Self {
+        let enregistrement = Journal::new(graine, &regles);
+        let regles = Arc::new(regles);
+        Self {
+            source,
+            solveur: Solveur::new(Arc::clone(&regles), Comptage::Meilleure),
+            regles,
+            graine,
+            ecran: Ecran::Reglage,
+            max_lancers: 3,
+            partie: None,
+            debut_partie: 0,
+            gardes: [false; 3],
+            animation: 0,
+            gardes_animation: [false; 3],
+            faces: [0; 3],
+            rng: StdRng::seed_from_u64(graine),
+            journal: Vec::new(),
+            message: String::new(),
+            enregistrement,
+            exit: false,
+        }
+    }
+
+    fn run(&mut self, terminal: &mut ratatui::Terminal<ratatui::backend::CrosstermBackend<io::Stdout>>) -> io::Result<()> {
+        let mut last_tick = Instant::now();
+        while !self.exit {
+            terminal.draw(|frame| frame.render_widget(&*self, frame.area()))?;
+
+            let timeout = TICK.saturating_sub(last_tick.elapsed());
+            if event::poll(timeout)? {
+                if let Event::Key(key_event) = event::read()? {
+                    if key_event.kind == KeyEventKind::Press {
+                        self.handle_key_event(key_event);
+                    }
+                }
+            }
+            if last_tick.elapsed() >= TICK {
+                self.tick();
+                last_tick = Instant::now();
+            }
+        }
+        Ok(())
+    }
+
+    fn handle_key_event(&mut self, key_event: KeyEvent) {
+        self.message.clear();
+        match self.ecran {
+            Ecran::Reglage => match key_event.code {
+                KeyCode::Left | KeyCode::Char('-') => self.max_lancers = (self.max_lancers - 1).max(1),
+                KeyCode::Right | KeyCode::Char('+') => self.max_lancers = (self.max_lancers + 1).min(MAX_LANCERS),
+                KeyCode::Enter | KeyCode::Char(' ') => self.nouvelle_partie(),
+                KeyCode::Char('q') | KeyCode::Esc => self.exit = true,
+                _ => {}
+            },
+            Ecran::Jeu if self.animation > 0 => {
+                if key_event.code == KeyCode::Char('q') {
+                    self.animation = 0;
+                    self.quitter_jeu();
+                }
+            }
+            Ecran::Jeu => match key_event.code {
+                KeyCode::Char(c @ '1'..='3') => {
+                    let i = c as usize - '1' as usize;
+                    if self.etat() == Some(Etat::Decision) {
+                        self.gardes[i] = !self.gardes[i];
+                    }
+                }
+                KeyCode::Enter | KeyCode::Char(' ') => match self.etat() {
+                    Some(Etat::Decision) => self.relancer(),
+                    _ => self.nouvelle_partie(),
+                },
+                KeyCode::Char('s') => self.arreter(),
+                KeyCode::Char('n') if self.etat() == Some(Etat::Terminee) => self.nouvelle_partie(),
+                KeyCode::Char('?') => self.conseil(),
+                KeyCode::Char('q') | KeyCode::Esc => self.quitter_jeu(),
+                _ => {}
+            },
+            Ecran::Bilan => {
+                if matches!(key_event.code, KeyCode::Char('q') | KeyCode::Esc | KeyCode::Enter) {
+                    self.exit = true;
+                }
+            }
+        }
+    }
+
+    fn tick(&mut self) {
+        if self.animation == 0 {
+            return;
+        }
+        self.animation -= 1;
+        for i in 0..3 {
+            if !self.gardes_animation[i] {
+                self.faces[i] = self.rng.gen_range(1..=6);
+            }
+        }
+    }
+
+    fn etat(&self) -> Option<Etat> {
+        self.partie.as_ref().map(Partie::etat)
+    }
+
+    /// Dés affichés : les vrais dés, ou des faces au hasard pendant l'animation.
+    fn des_affiches(&self) -> [u8; 3] {
+        let des = self.partie.as_ref().map_or([0; 3], Partie::des);
+        if self.animation == 0 {
+            return des;
+        }
+        std::array::from_fn(|i| if self.gardes_animation[i] { des[i] } else { self.faces[i] })
+    }
+
+    fn nouvelle_partie(&mut self) {
+        self.ecran = Ecran::Jeu;
+        self.partie = Some(Partie::avec_regles(self.max_lancers, Arc::clone(&self.regles)).expect("lancers entre 1 et 15"));
+        self.debut_partie = maintenant();
+        self.enregistrement.nouvelle_partie(self.max_lancers);
+        self.journal.clear();
+        self.journal.push(format!("Partie {} :", self.enregistrement.scores.len() + 1));
+        self.gardes = [false; 3];
+        self.lancer();
+    }
+
+    fn relancer(&mut self) {
+        let gardes = self.gardes;
+        let Some(partie) = self.partie.as_mut() else { return };
+        match partie.garder(gardes) {
+            Ok(()) => {
+                self.enregistrement.noter(Coup::Garder { gardes });
+                self.lancer();
+            }
+            Err(message) => self.message = message,
+        }
+    }
+
+    fn lancer(&mut self) {
+        let Some(partie) = self.partie.as_mut() else { return };
+        self.gardes_animation = self.gardes;
+        match partie.lancer(self.source) {
+            Ok(evenements) => {
+                self.animation = IMAGES_ANIMATION + 1;
+                self.tick();
+                self.gardes = [false; 3];
+                self.suivre(evenements);
+            }
+            Err(message) => self.message = message,
+        }
+    }
+
+    fn arreter(&mut self) {
+        let Some(partie) = self.partie.as_mut() else { return };
+        match partie.arreter() {
+            Ok(evenement) => {
+                self.enregistrement.noter(Coup::Arreter);
+                self.suivre(vec![evenement]);
+            }
+            Err(message) => self.message = message,
+        }
+    }
+
+    fn conseil(&mut self) {
+        let Some(partie) = self.partie.as_ref().filter(|p| p.etat() == Etat::Decision) else { return };
+        let conseil = self.solveur.conseil_partie(partie);
+        self.message = match conseil.gardes {
+            None => format!("Conseil : s'arrêter ({:.0} point(s))", conseil.esperance),
+            Some(gardes) => {
+                let touches: Vec<String> = (0..3).filter(|&i| gardes[i]).map(|i| (i + 1).to_string()).collect();
+                if touches.is_empty() {
+                    format!("Conseil : tout relancer (espérance {:.2})", conseil.esperance)
+                } else {
+                    format!("Conseil : garder {} (espérance {:.2})", touches.join(" et "), conseil.esperance)
+                }
+            }
+        };
+        if let Some(gardes) = conseil.gardes {
+            self.gardes = gardes;
+        }
+    }
+
+    fn suivre(&mut self, evenements: Vec<Evenement>) {
+        self.enregistrement.noter_evenements(&evenements);
+        for evenement in evenements {
+            let ligne = match evenement {
+                Evenement::DesLances { lancer, des, points } => format!(
+                    "Lancer {} : {} {} {} - {} point(s) ({})",
+                    lancer,
+                    des[0],
+                    des[1],
+                    des[2],
+                    points,
+                    self.regles.nom(&des)
+                ),
+                Evenement::Quatre21 => "Bravo ! Vous avez obtenu 421 !".to_string(),
+                Evenement::PartieTerminee(resultat) => {
+                    let numero = self.enregistrement.scores.len() as u32 + 1;
+                    let score = Score::new(numero, &resultat, self.debut_partie, maintenant());
+                    self.enregistrement.terminer_partie(score);
+                    format!("Score retenu : {} points avec {}", resultat.points, resultat.combinaison())
+                }
+            };
+            self.journal.push(ligne);
+        }
+        let trop = self.journal.len().saturating_sub(JOURNAL_MAX);
+        self.journal.drain(..trop);
+    }
+
+    /// Une partie en cours compte avec sa meilleure combinaison.
+    fn quitter_jeu(&mut self) {
+        if self.etat() == Some(Etat::Decision) {
+            self.arreter();
+        }
+        self.ecran = Ecran::Bilan;
+    }
+
+    fn instructions(&self) -> Line<'static> {
+        let touches: Vec<(&str, &str)> = match (self.ecran, self.etat()) {
+            (Ecran::Reglage, _) => vec![(" Lancers ", "<Gauche/Droite>"), (" Jouer ", "<Entrée>")],
+            (Ecran::Jeu, Some(Etat::Decision)) => vec![
+                (" Garder ", "<1-3>"),
+                (" Relancer ", "<Espace>"),
+                (" S'arrêter ", "<S>"),
+                (" Conseil ", "<?>"),
+            ],
+            (Ecran::Jeu, _) => vec![(" Nouvelle partie ", "<N>")],
+            (Ecran::Bilan, _) => vec![],
+        };
+        let mut spans: Vec<ratatui::text::Span> = Vec::new();
+        for (action, touche) in touches {
+            spans.push(action.into());
+            spans.push(touche.blue().bold());
+        }
+        spans.push(" Quitter ".into());
+        spans.push("<Q> ".blue().bold());
+        Line::from(spans)
+    }
+
+    fn render_reglage(&self, area: Rect, buf: &mut Buffer) {
+        let lignes = vec![
+            Line::from("Bienvenue dans le jeu du 421 !".bold()),
+            Line::from(""),
+            Line::from(vec![
+                "Lancers par partie : ".into(),
+                format!("< {} >", self.max_lancers).yellow().bold(),
+            ]),
+            Line::from(""),
+            Line::from(format!("Graine : {}", self.graine)),
+        ];
+        Paragraph::new(lignes).centered().render(area, buf);
+    }
+
+    fn render_des(&self, area: Rect, buf: &mut Buffer) {
+        let des = self.des_affiches();
+        let gardes = if self.animation > 0 { self.gardes_animation } else { self.gardes };
+        let colonnes = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Length(13); 3])
+            .flex(ratatui::layout::Flex::Center)
+            .split(area);
+        for (i, colonne) in colonnes.iter().enumerate() {
+            let style = match (self.animation > 0 && !gardes[i], gardes[i]) {
+                (true, _) => Style::default().fg(Color::DarkGray),
+                (false, true) => Style::default().fg(Color::Yellow).bold(),
+                (false, false) => Style::default().fg(Color::White),
+            };
+            let mut lignes: Vec<Line> = face(des[i]).into_iter().map(|l| Line::styled(l, style)).collect();
+            let etiquette = if gardes[i] { format!("[{}] gardé", i + 1) } else { format!("[{}]", i + 1) };
+            lignes.push(Line::styled(etiquette, style));
+            Paragraph::new(lignes).centered().render(*colonne, buf);
+        }
+    }
+
+    fn render_jeu(&self, area: Rect, buf: &mut Buffer) {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(1),
+                Constraint::Length(7),
+                Constraint::Length(1),
+                Constraint::Min(0),
+            ])
+            .split(area);
+
+        if let Some(partie) = &self.partie {
+            let etat = match partie.etat() {
+                Etat::Terminee => "partie terminée".to_string(),
+                _ => format!("{} lancer(s) restant(s)", partie.lancers_restants()),
+            };
+            let ligne = format!("Lancer {}/{} - {}", partie.lancers(), partie.max_lancers(), etat);
+            Paragraph::new(Line::from(ligne.bold())).centered().render(chunks[0], buf);
+        }
+        self.render_des(chunks[1], buf);
+        if !self.message.is_empty() {
+            Paragraph::new(Line::from(self.message.clone()).fg(Color::Yellow).centered()).render(chunks[2], buf);
+        }
+        // Le journal n'est complet qu'une fois les dés arrêtés.
+        let journal = match self.animation {
+            0 => &self.journal[..],
+            _ => &self.journal[..self.journal.len().min(1)],
+        };
+        let lignes: Vec<Line> = journal.iter().map(|l| Line::from(l.as_str())).collect();
+        Paragraph::new(lignes)
+            .block(Block::bordered().title(" Journal "))
+            .render(chunks[3], buf);
+    }
+
+    /// Scores de la session sous forme de tableau, avec une ligne de total.
+    fn table_scores(&self) -> Table<'_> {
+        let total: u32 = self.enregistrement.scores.iter().map(|s| s.points).sum();
+        let lancers: u32 = self.enregistrement.scores.iter().map(|s| s.lancers).sum();
+        let mut lignes: Vec<Row> = self
+            .enregistrement
+            .scores
+            .iter()
+            .map(|s| {
+                Row::new(vec![
+                    s.partie.to_string(),
+                    s.points.to_string(),
+                    s.combinaison.clone(),
+                    s.lancers.to_string(),
+                ])
+            })
+            .collect();
+        lignes.push(Row::new(vec!["Total".to_string(), total.to_string(), String::new(), lancers.to_string()]).bold());
+        Table::new(
+            lignes,
+            [Constraint::Length(7), Constraint::Length(6), Constraint::Length(11), Constraint::Length(7)],
+        )
+        .header(Row::new(["Partie", "Points", "Combinaison", "Lancers"]).bold().underlined())
+    }
+
+    fn render_scores(&self, area: Rect, buf: &mut Buffer) {
+        let block = Block::bordered().title(" Scores ");
+        let inner = block.inner(area);
+        block.render(area, buf);
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(0), Constraint::Length(3)])
+            .split(inner);
+        Widget::render(self.table_scores(), chunks[0], buf);
+
+        let parties = self.enregistrement.scores.len().max(1) as f64;
+        let moyenne = self.enregistrement.scores.iter().map(|s| s.points as f64).sum::<f64>() / parties;
+        let meilleur = self.enregistrement.scores.iter().map(|s| s.points).max().unwrap_or(0);
+        Paragraph::new(vec![
+            Line::from(format!("Moyenne : {:.2} point(s)", moyenne)),
+            Line::from(format!("Meilleur : {} point(s)", meilleur)),
+            Line::from(format!("Graine : {}", self.graine)),
+        ])
+        .render(chunks[1], buf);
+    }
+
+    fn render_bilan(&self, area: Rect, buf: &mut Buffer) {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(0), Constraint::Length(5)])
+            .split(area);
+        let block = Block::bordered().title(" Tableau des scores ".bold());
+        Widget::render(self.table_scores().block(block), chunks[0], buf);
+
+        let parties = self.enregistrement.scores.len().max(1) as f64;
+        let points: u32 = self.enregistrement.scores.iter().map(|s| s.points).sum();
+        let lancers: u32 = self.enregistrement.scores.iter().map(|s| s.lancers).sum();
+        let mut lignes = vec![
+            Line::from(format!("Nombre de parties : {}", self.enregistrement.scores.len())),
+            Line::from(format!("Moyenne de points par partie : {:.2}", points as f64 / parties)),
+            Line::from(format!("Moyenne de lancers par partie : {:.2}", lancers as f64 / parties)),
+        ];
+        if let Some(meilleur) = self.enregistrement.scores.iter().max_by_key(|s| s.points) {
+            lignes.push(Line::from(format!(
+                "Meilleur score : {} points (partie {}, obtenu au lancer {})",
+                meilleur.points, meilleur.partie, meilleur.lancer_record
+            )));
+        }
+        lignes.push(Line::from(format!("Graine : {} (rejouez cette session avec --seed {})", self.graine, self.graine)));
+        Paragraph::new(lignes).render(chunks[1], buf);
+    }
+}
+
+// Implémentation du trait Widget pour &Jeu421
+impl Widget for &Jeu421<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let title = Line::from(" Jeu du 421 ".bold());
+        let block = Block::bordered()
+            .title(title.centered())
+            .title_bottom(self.instructions().centered())
+            .border_set(border::THICK);
+        let inner = block.inner(area);
+        block.render(area, buf);
+
+        match self.ecran {
+            Ecran::Reglage => self.render_reglage(inner, buf),
+            Ecran::Bilan => self.render_bilan(inner, buf),
+            Ecran::Jeu => {
+                let chunks = Layout::default()
+                    .direction(Direction::Horizontal)
+                    .constraints([Constraint::Min(45), Constraint::Length(38)])
+                    .split(inner);
+                self.render_jeu(chunks[0], buf);
+                self.render_scores(chunks[1], buf);
+            }
+        }
+    }
+}
+
+// Déclaration du module de tests
+#[cfg(test)]
+#[path = "tests/tui.rs"]
+mod tests;